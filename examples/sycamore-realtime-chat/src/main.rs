@@ -1,16 +1,9 @@
-use serde::{Deserialize, Serialize};
-use supabase_js_rs::create_client;
+use supabase_js_rs::{create_client, PostgresChangesEvent, PostgresChangesFilter};
 use sycamore::prelude::*;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsValue;
 use web_sys::console::{log_1, log_2};
 
-#[derive(Serialize, Deserialize)]
-pub struct RealtimePostgresChangesFilter {
-    pub event: String,
-    pub schema: String,
-}
-
 fn main() {
     let supabase_url = std::env!("SUPABASE_URL");
     let supabase_key = std::env!("SUPABASE_KEY");
@@ -27,19 +20,12 @@ fn main() {
                 log_2(&status, &error);
             });
 
-        let filter = RealtimePostgresChangesFilter {
-            event: "*".to_string(),
-            schema: "*".to_string(),
-        };
+        let filter = PostgresChangesFilter::new(PostgresChangesEvent::All).schema("*");
 
         client
             .get()
             .channel("*")
-            .on(
-                "postgres_changes",
-                &serde_wasm_bindgen::to_value(&filter).unwrap(),
-                &payload,
-            )
+            .on("postgres_changes", &filter.into(), &payload)
             .subscribe(Some(&subscribition_callback));
 
         payload.forget();
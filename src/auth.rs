@@ -0,0 +1,223 @@
+//! Typed responses of [`Auth`] methods.
+//!
+//! Deserialize a response with [`IntoSupabaseResult::into_data_as`].
+
+#![cfg(feature = "serde")]
+
+use serde::Deserialize;
+use wasm_bindgen::JsValue;
+
+use crate::{Auth, Credentials, IntoSupabaseResult, SupabaseError};
+
+/// Enrolled MFA factor
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Factor {
+    pub id: String,
+    pub friendly_name: Option<String>,
+    /// `totp` or `phone`.
+    pub factor_type: String,
+    /// `verified` or `unverified`.
+    pub status: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Response of [`Mfa::list_factors`](crate::Mfa::list_factors)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Factors {
+    /// Every factor, including unverified ones.
+    pub all: Vec<Factor>,
+    /// Verified TOTP factors.
+    #[serde(default)]
+    pub totp: Vec<Factor>,
+    /// Verified phone factors.
+    #[serde(default)]
+    pub phone: Vec<Factor>,
+}
+
+/// QR code and secret to add a TOTP factor to an authenticator app
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TotpSecret {
+    /// SVG image as a data URL.
+    pub qr_code: String,
+    pub secret: String,
+    /// `otpauth://` URI encoded in the QR code.
+    pub uri: String,
+}
+
+/// Response of [`Mfa::enroll`](crate::Mfa::enroll)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MfaEnrollment {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub factor_type: String,
+    pub friendly_name: Option<String>,
+    /// Present for TOTP factors.
+    pub totp: Option<TotpSecret>,
+    /// Present for phone factors.
+    pub phone: Option<String>,
+}
+
+/// Response of [`Mfa::challenge`](crate::Mfa::challenge)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MfaChallenge {
+    pub id: String,
+    /// Unix timestamp in seconds.
+    pub expires_at: i64,
+}
+
+/// Authenticator Assurance Level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthenticatorAssuranceLevel {
+    /// Signed in with a single factor.
+    Aal1,
+    /// Verified a second factor.
+    Aal2,
+}
+
+/// Method used to authenticate the session
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AuthenticationMethod {
+    /// e.g. `password`, `otp` or `totp`.
+    pub method: String,
+    pub timestamp: i64,
+}
+
+/// Response of [`Mfa::get_authenticator_assurance_level`](crate::Mfa::get_authenticator_assurance_level)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthenticatorAssurance {
+    pub current_level: Option<AuthenticatorAssuranceLevel>,
+    /// `aal2` once the user has a verified factor.
+    pub next_level: Option<AuthenticatorAssuranceLevel>,
+    #[serde(default)]
+    pub current_authentication_methods: Vec<AuthenticationMethod>,
+}
+
+/// Auth session
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Session {
+    pub access_token: String,
+    pub refresh_token: String,
+    /// Seconds until `access_token` expires.
+    pub expires_in: i64,
+    /// Unix timestamp in seconds when `access_token` expires.
+    pub expires_at: Option<i64>,
+    pub token_type: String,
+    /// OAuth provider token, if the user signed in with a third-party provider.
+    pub provider_token: Option<String>,
+    pub provider_refresh_token: Option<String>,
+    pub user: User,
+}
+
+/// Auth user
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct User {
+    pub id: String,
+    pub aud: String,
+    pub role: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    #[serde(default, with = "serde_wasm_bindgen::preserve")]
+    pub app_metadata: JsValue,
+    #[serde(default, with = "serde_wasm_bindgen::preserve")]
+    pub user_metadata: JsValue,
+    #[serde(default)]
+    pub identities: Vec<UserIdentity>,
+    #[serde(default)]
+    pub factors: Vec<Factor>,
+    #[serde(default)]
+    pub is_anonymous: bool,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub confirmed_at: Option<String>,
+    pub email_confirmed_at: Option<String>,
+    pub phone_confirmed_at: Option<String>,
+    pub last_sign_in_at: Option<String>,
+}
+
+/// Identity linked to a user
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct UserIdentity {
+    pub id: String,
+    pub identity_id: Option<String>,
+    pub user_id: String,
+    /// e.g. `email`, `github` or `google`.
+    pub provider: String,
+    #[serde(default, with = "serde_wasm_bindgen::preserve")]
+    pub identity_data: JsValue,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub last_sign_in_at: Option<String>,
+}
+
+/// Response of sign up, sign in and session refresh
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AuthResponse {
+    pub user: Option<User>,
+    /// `None` until the user confirms their email or phone.
+    pub session: Option<Session>,
+}
+
+#[derive(Deserialize)]
+struct SessionData {
+    session: Option<Session>,
+}
+
+#[derive(Deserialize)]
+struct UserData {
+    user: User,
+}
+
+impl Auth {
+    /// # Retrieve a session as [`Session`]
+    ///
+    /// Returns `None` if there is no signed in user.
+    ///
+    pub async fn get_session_typed(&self) -> Result<Option<Session>, SupabaseError> {
+        let data: SessionData = self.get_session().await.into_data_as()?;
+        Ok(data.session)
+    }
+
+    /// # Retrieve a new session as [`AuthResponse`]
+    ///
+    pub async fn refresh_session_typed(&self) -> Result<AuthResponse, SupabaseError> {
+        self.refresh_session().await.into_data_as()
+    }
+
+    /// # Retrieve a user as [`User`]
+    ///
+    pub async fn get_user_typed(&self, jwt: Option<&str>) -> Result<User, SupabaseError> {
+        let data: UserData = self.get_user(jwt).await.into_data_as()?;
+        Ok(data.user)
+    }
+
+    /// # Create a new user
+    ///
+    /// ```ignore
+    /// let res: AuthResponse = client
+    ///     .auth()
+    ///     .sign_up_typed(Credentials {
+    ///         email: "user@example.com".into(),
+    ///         password: "password".into(),
+    ///     })
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn sign_up_typed(
+        &self,
+        credentials: Credentials,
+    ) -> Result<AuthResponse, SupabaseError> {
+        self.sign_up(credentials).await.into_data_as()
+    }
+
+    /// # Sign in a user
+    ///
+    pub async fn sign_in_with_password_typed(
+        &self,
+        credentials: Credentials,
+    ) -> Result<AuthResponse, SupabaseError> {
+        self.sign_in_with_password(credentials).await.into_data_as()
+    }
+}
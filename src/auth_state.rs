@@ -0,0 +1,94 @@
+//! Typed auth state change events.
+
+#[cfg(feature = "serde")]
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+#[cfg(feature = "serde")]
+use crate::error::{from_value, get};
+#[cfg(feature = "serde")]
+use crate::{Auth, AuthSubscription, Session, SupabaseError};
+
+/// Auth event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthChangeEvent {
+    InitialSession,
+    SignedIn,
+    SignedOut,
+    TokenRefreshed,
+    UserUpdated,
+    PasswordRecovery,
+    MfaChallengeVerified,
+    /// Event unknown to this crate.
+    Other(String),
+}
+
+impl From<&str> for AuthChangeEvent {
+    fn from(event: &str) -> Self {
+        match event {
+            "INITIAL_SESSION" => AuthChangeEvent::InitialSession,
+            "SIGNED_IN" => AuthChangeEvent::SignedIn,
+            "SIGNED_OUT" => AuthChangeEvent::SignedOut,
+            "TOKEN_REFRESHED" => AuthChangeEvent::TokenRefreshed,
+            "USER_UPDATED" => AuthChangeEvent::UserUpdated,
+            "PASSWORD_RECOVERY" => AuthChangeEvent::PasswordRecovery,
+            "MFA_CHALLENGE_VERIFIED" => AuthChangeEvent::MfaChallengeVerified,
+            event => AuthChangeEvent::Other(event.to_string()),
+        }
+    }
+}
+
+/// Auth state listener
+///
+/// Owns the callback and unsubscribes from auth events when dropped.
+#[cfg(feature = "serde")]
+pub struct Subscription {
+    subscription: AuthSubscription,
+    _callback: Closure<dyn FnMut(JsValue, JsValue)>,
+}
+
+#[cfg(feature = "serde")]
+impl Subscription {
+    /// Stop listening to auth events.
+    pub fn unsubscribe(self) {}
+}
+
+#[cfg(feature = "serde")]
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.subscription.unsubscribe();
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Auth {
+    /// # Listen to auth events
+    ///
+    /// The session is `Ok(None)` when signed out and an error when it fails to deserialize.
+    /// Keep the returned [`Subscription`] alive for as long as events should be delivered.
+    ///
+    /// ```ignore
+    /// let subscription = client.auth().on_auth_state_change_typed(|event, session| {
+    ///     match session {
+    ///         Ok(Some(session)) => { /* signed in */ }
+    ///         Ok(None) => { /* signed out */ }
+    ///         Err(error) => log(&error.to_string()),
+    ///     }
+    /// });
+    /// ```
+    ///
+    pub fn on_auth_state_change_typed<F>(&self, mut callback: F) -> Subscription
+    where
+        F: FnMut(AuthChangeEvent, Result<Option<Session>, SupabaseError>) + 'static,
+    {
+        let closure: Closure<dyn FnMut(JsValue, JsValue)> =
+            Closure::new(move |event: JsValue, session: JsValue| {
+                let event = AuthChangeEvent::from(event.as_string().unwrap_or_default().as_str());
+                callback(event, from_value(session));
+            });
+        let res = self.on_auth_state_change_js(&closure);
+        Subscription {
+            subscription: get(&get(&res, "data"), "subscription").unchecked_into(),
+            _callback: closure,
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Options to create a [`SupabaseClient`].
+
+use js_sys::{Function, Object};
+use wasm_bindgen::JsValue;
+
+use crate::error::set;
+use crate::SupabaseClient;
+
+/// OAuth flow used by Auth
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowType {
+    Implicit,
+    Pkce,
+}
+
+impl FlowType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FlowType::Implicit => "implicit",
+            FlowType::Pkce => "pkce",
+        }
+    }
+}
+
+/// Options of [`create_client_with_options`]
+///
+/// Converts into the `SupabaseClientOptions` object of `supabase.createClient`.
+#[derive(Debug, Clone, Default)]
+pub struct ClientOptions {
+    schema: Option<String>,
+    persist_session: Option<bool>,
+    auto_refresh_token: Option<bool>,
+    detect_session_in_url: Option<bool>,
+    flow_type: Option<FlowType>,
+    storage: Option<JsValue>,
+    storage_key: Option<String>,
+    headers: Vec<(String, String)>,
+    fetch: Option<Function>,
+    realtime_params: Vec<(String, JsValue)>,
+}
+
+impl ClientOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Postgres schema to query, `public` by default.
+    pub fn schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// Persist the session in storage, `true` by default.
+    pub fn persist_session(mut self, persist_session: bool) -> Self {
+        self.persist_session = Some(persist_session);
+        self
+    }
+
+    /// Refresh the access token before it expires, `true` by default.
+    pub fn auto_refresh_token(mut self, auto_refresh_token: bool) -> Self {
+        self.auto_refresh_token = Some(auto_refresh_token);
+        self
+    }
+
+    /// Detect OAuth grants in the URL, `true` by default.
+    pub fn detect_session_in_url(mut self, detect_session_in_url: bool) -> Self {
+        self.detect_session_in_url = Some(detect_session_in_url);
+        self
+    }
+
+    pub fn flow_type(mut self, flow_type: FlowType) -> Self {
+        self.flow_type = Some(flow_type);
+        self
+    }
+
+    /// Object with `getItem`, `setItem` and `removeItem` to store the session in,
+    /// `localStorage` by default.
+    pub fn storage(mut self, storage: JsValue) -> Self {
+        self.storage = Some(storage);
+        self
+    }
+
+    /// Key to store the session under.
+    pub fn storage_key(mut self, storage_key: &str) -> Self {
+        self.storage_key = Some(storage_key.to_string());
+        self
+    }
+
+    /// Add a header sent with every request.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Custom `fetch` implementation.
+    pub fn fetch(mut self, fetch: Function) -> Self {
+        self.fetch = Some(fetch);
+        self
+    }
+
+    /// Add a Realtime parameter, e.g. `eventsPerSecond`.
+    pub fn realtime_param(mut self, name: &str, value: impl Into<JsValue>) -> Self {
+        self.realtime_params.push((name.to_string(), value.into()));
+        self
+    }
+}
+
+impl From<ClientOptions> for JsValue {
+    fn from(options: ClientOptions) -> Self {
+        let object = Object::new();
+
+        if let Some(schema) = &options.schema {
+            let db = Object::new();
+            set(&db, "schema", &JsValue::from_str(schema));
+            set(&object, "db", &db);
+        }
+
+        let auth = Object::new();
+        if let Some(persist_session) = options.persist_session {
+            set(
+                &auth,
+                "persistSession",
+                &JsValue::from_bool(persist_session),
+            );
+        }
+        if let Some(auto_refresh_token) = options.auto_refresh_token {
+            set(
+                &auth,
+                "autoRefreshToken",
+                &JsValue::from_bool(auto_refresh_token),
+            );
+        }
+        if let Some(detect_session_in_url) = options.detect_session_in_url {
+            let detect_session_in_url = JsValue::from_bool(detect_session_in_url);
+            set(&auth, "detectSessionInUrl", &detect_session_in_url);
+        }
+        if let Some(flow_type) = options.flow_type {
+            set(&auth, "flowType", &JsValue::from_str(flow_type.as_str()));
+        }
+        if let Some(storage) = &options.storage {
+            set(&auth, "storage", storage);
+        }
+        if let Some(storage_key) = &options.storage_key {
+            set(&auth, "storageKey", &JsValue::from_str(storage_key));
+        }
+        set(&object, "auth", &auth);
+
+        let global = Object::new();
+        if !options.headers.is_empty() {
+            let headers = Object::new();
+            for (name, value) in &options.headers {
+                set(&headers, name, &JsValue::from_str(value));
+            }
+            set(&global, "headers", &headers);
+        }
+        if let Some(fetch) = &options.fetch {
+            set(&global, "fetch", fetch);
+        }
+        set(&object, "global", &global);
+
+        if !options.realtime_params.is_empty() {
+            let params = Object::new();
+            for (name, value) in &options.realtime_params {
+                set(&params, name, value);
+            }
+            let realtime = Object::new();
+            set(&realtime, "params", &params);
+            set(&object, "realtime", &realtime);
+        }
+
+        object.into()
+    }
+}
+
+/// # Create client with options
+///
+/// ```ignore
+/// let client = supabase_js_rs::create_client_with_options(
+///     "https://xyzcompany.supabase.co",
+///     "public-anon-key",
+///     ClientOptions::new()
+///         .schema("private")
+///         .persist_session(false)
+///         .header("x-my-custom-header", "my-app-name"),
+/// );
+/// ```
+///
+pub fn create_client_with_options(
+    supabase_url: &str,
+    supabase_key: &str,
+    options: ClientOptions,
+) -> SupabaseClient {
+    crate::create_client_js(supabase_url, supabase_key, options.into())
+}
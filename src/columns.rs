@@ -0,0 +1,254 @@
+//! Builder for the columns passed to `select`.
+
+use std::fmt;
+
+/// Columns to retrieve
+///
+/// Renders into the `select` syntax of PostgREST with [`Display`](fmt::Display).
+///
+/// ```ignore
+/// use supabase_js_rs::{Columns, Embed};
+///
+/// let columns = Columns::new()
+///     .column("id")
+///     .alias("country", "name")
+///     .json_text("metadata", &["capital", "name"])
+///     .embed(Embed::new("cities").inner().columns(Columns::new().column("name")));
+///
+/// // id, country:name, metadata->capital->>name, cities!inner(name)
+/// let res = client
+///     .from("countries")
+///     .select_(Some(&columns.to_string()))
+///     .eq("cities.name", &JsValue::from("Bali"))
+///     .await;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Columns {
+    columns: Vec<String>,
+}
+
+impl Columns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every column, `*`.
+    pub fn all(self) -> Self {
+        self.push("*".to_string())
+    }
+
+    /// Column by name.
+    pub fn column(self, name: &str) -> Self {
+        self.push(name.to_string())
+    }
+
+    /// Column renamed to `alias` in the result, `alias:name`.
+    pub fn alias(self, alias: &str, name: &str) -> Self {
+        self.push(format!("{}:{}", alias, name))
+    }
+
+    /// Column cast to another type, `name::type`.
+    pub fn cast(self, name: &str, cast: &str) -> Self {
+        self.push(format!("{}::{}", name, cast))
+    }
+
+    /// JSON value at `path` in a `json` or `jsonb` column, `column->a->b`.
+    pub fn json(self, column: &str, path: &[&str]) -> Self {
+        self.push(json_path(column, path, false))
+    }
+
+    /// Text value at `path` in a `json` or `jsonb` column, `column->a->>b`.
+    pub fn json_text(self, column: &str, path: &[&str]) -> Self {
+        self.push(json_path(column, path, true))
+    }
+
+    /// Embedded resource from a related table.
+    pub fn embed(self, embed: Embed) -> Self {
+        self.push(embed.to_string())
+    }
+
+    fn push(mut self, column: String) -> Self {
+        self.columns.push(column);
+        self
+    }
+}
+
+impl fmt::Display for Columns {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.columns.is_empty() {
+            return write!(f, "*");
+        }
+        write!(f, "{}", self.columns.join(", "))
+    }
+}
+
+/// Join type of an embedded resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Join {
+    Inner,
+    Left,
+}
+
+/// Embedded resource
+///
+/// Renders as `alias:table!hint!inner(columns)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Embed {
+    table: String,
+    alias: Option<String>,
+    hint: Option<String>,
+    join: Option<Join>,
+    columns: Columns,
+}
+
+impl Embed {
+    /// Embed rows of a related table.
+    pub fn new(table: &str) -> Self {
+        Self {
+            table: table.to_string(),
+            alias: None,
+            hint: None,
+            join: None,
+            columns: Columns::new(),
+        }
+    }
+
+    /// Name of the resource in the result.
+    pub fn alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    /// Foreign key or column to disambiguate multiple relationships.
+    pub fn hint(mut self, hint: &str) -> Self {
+        self.hint = Some(hint.to_string());
+        self
+    }
+
+    /// Only return parent rows with a matching row, so filters on the resource filter the parent.
+    pub fn inner(mut self) -> Self {
+        self.join = Some(Join::Inner);
+        self
+    }
+
+    /// Return parent rows without a matching row too, the default.
+    pub fn left(mut self) -> Self {
+        self.join = Some(Join::Left);
+        self
+    }
+
+    /// Columns of the resource, `*` by default.
+    pub fn columns(mut self, columns: Columns) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl fmt::Display for Embed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(alias) = &self.alias {
+            write!(f, "{}:", alias)?;
+        }
+        write!(f, "{}", self.table)?;
+        if let Some(hint) = &self.hint {
+            write!(f, "!{}", hint)?;
+        }
+        match self.join {
+            Some(Join::Inner) => write!(f, "!inner")?,
+            Some(Join::Left) => write!(f, "!left")?,
+            None => {}
+        }
+        write!(f, "({})", self.columns)
+    }
+}
+
+fn json_path(column: &str, path: &[&str], text: bool) -> String {
+    let mut rendered = column.to_string();
+    for (i, key) in path.iter().enumerate() {
+        let arrow = if text && i == path.len() - 1 {
+            "->>"
+        } else {
+            "->"
+        };
+        rendered.push_str(arrow);
+        rendered.push_str(key);
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_columns() {
+        assert_eq!(Columns::new().to_string(), "*");
+        assert_eq!(Columns::new().all().to_string(), "*");
+        assert_eq!(
+            Columns::new()
+                .column("id")
+                .alias("country", "name")
+                .cast("population", "text")
+                .to_string(),
+            "id, country:name, population::text"
+        );
+    }
+
+    #[test]
+    fn renders_json_paths() {
+        assert_eq!(json_path("metadata", &[], false), "metadata");
+        assert_eq!(json_path("metadata", &[], true), "metadata");
+        assert_eq!(
+            json_path("metadata", &["capital"], false),
+            "metadata->capital"
+        );
+        assert_eq!(
+            json_path("metadata", &["capital"], true),
+            "metadata->>capital"
+        );
+        assert_eq!(
+            Columns::new()
+                .json("metadata", &["capital", "name"])
+                .json_text("metadata", &["capital", "name"])
+                .to_string(),
+            "metadata->capital->name, metadata->capital->>name"
+        );
+    }
+
+    #[test]
+    fn renders_embeds() {
+        assert_eq!(Embed::new("cities").to_string(), "cities(*)");
+        assert_eq!(
+            Embed::new("cities").alias("towns").to_string(),
+            "towns:cities(*)"
+        );
+        assert_eq!(
+            Embed::new("users").hint("author_id").to_string(),
+            "users!author_id(*)"
+        );
+        assert_eq!(
+            Embed::new("cities")
+                .inner()
+                .columns(Columns::new().column("name"))
+                .to_string(),
+            "cities!inner(name)"
+        );
+        assert_eq!(Embed::new("cities").left().to_string(), "cities!left(*)");
+        assert_eq!(
+            Embed::new("users")
+                .alias("author")
+                .hint("author_id")
+                .inner()
+                .columns(Columns::new().column("id").column("name"))
+                .to_string(),
+            "author:users!author_id!inner(id, name)"
+        );
+        assert_eq!(
+            Columns::new()
+                .column("id")
+                .embed(Embed::new("cities").columns(Columns::new().embed(Embed::new("streets"))))
+                .to_string(),
+            "id, cities(streets(*))"
+        );
+    }
+}
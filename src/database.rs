@@ -0,0 +1,1349 @@
+//! Rust-side helpers for [`Database`] queries.
+
+#[cfg(feature = "serde")]
+use std::{future::Future, future::IntoFuture, marker::PhantomData, pin::Pin};
+
+use js_sys::Object;
+use wasm_bindgen::JsValue;
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+#[cfg(feature = "serde")]
+use crate::error::{from_value, to_value};
+use crate::error::{get, resolve, set, IntoSupabaseResult};
+use crate::{Database, SupabaseClient, SupabaseError};
+
+/// Algorithm used to count rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    /// Exact but slow count performed by `COUNT(*)`.
+    Exact,
+    /// Approximated but fast count from Postgres statistics.
+    Planned,
+    /// Exact count for low numbers and planned count for high numbers.
+    Estimated,
+}
+
+impl Count {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Count::Exact => "exact",
+            Count::Planned => "planned",
+            Count::Estimated => "estimated",
+        }
+    }
+}
+
+/// Options to fetch data
+#[derive(Debug, Clone, Default)]
+pub struct SelectOptions {
+    count: Option<Count>,
+    head: Option<bool>,
+}
+
+impl SelectOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count rows, returned as [`QueryResponse::count`].
+    pub fn count(mut self, count: Count) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Return no data, which is useful to only count rows.
+    pub fn head(mut self, head: bool) -> Self {
+        self.head = Some(head);
+        self
+    }
+}
+
+impl From<SelectOptions> for JsValue {
+    fn from(options: SelectOptions) -> Self {
+        let object = Object::new();
+        set_count(&object, options.count);
+        if let Some(head) = options.head {
+            set(&object, "head", &JsValue::from_bool(head));
+        }
+        object.into()
+    }
+}
+
+/// Options to call a Postgres function
+#[derive(Debug, Clone, Default)]
+pub struct RpcOptions {
+    count: Option<Count>,
+    head: Option<bool>,
+    get: Option<bool>,
+}
+
+impl RpcOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count rows returned by a set-returning function.
+    pub fn count(mut self, count: Count) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Only return the count, without data.
+    pub fn head(mut self, head: bool) -> Self {
+        self.head = Some(head);
+        self
+    }
+
+    /// Call the function with a read-only GET request.
+    pub fn get(mut self, get: bool) -> Self {
+        self.get = Some(get);
+        self
+    }
+}
+
+impl From<RpcOptions> for JsValue {
+    fn from(options: RpcOptions) -> Self {
+        let object = Object::new();
+        set_count(&object, options.count);
+        if let Some(head) = options.head {
+            set(&object, "head", &JsValue::from_bool(head));
+        }
+        if let Some(get) = options.get {
+            set(&object, "get", &JsValue::from_bool(get));
+        }
+        object.into()
+    }
+}
+
+/// Format of the execution plan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplainFormat {
+    Text,
+    Json,
+}
+
+/// Options to retrieve the execution plan
+///
+/// `explain()` has to be enabled with `db_plan_enabled` on the project first.
+#[derive(Debug, Clone, Default)]
+pub struct ExplainOptions {
+    analyze: Option<bool>,
+    verbose: Option<bool>,
+    settings: Option<bool>,
+    buffers: Option<bool>,
+    wal: Option<bool>,
+    format: Option<ExplainFormat>,
+}
+
+impl ExplainOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Execute the query and include the actual run time.
+    pub fn analyze(mut self, analyze: bool) -> Self {
+        self.analyze = Some(analyze);
+        self
+    }
+
+    /// Include the output columns and other details.
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
+    }
+
+    /// Include the configuration parameters that affect the plan.
+    pub fn settings(mut self, settings: bool) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Include buffer usage, requires `analyze`.
+    pub fn buffers(mut self, buffers: bool) -> Self {
+        self.buffers = Some(buffers);
+        self
+    }
+
+    /// Include WAL record generation, requires `analyze`.
+    pub fn wal(mut self, wal: bool) -> Self {
+        self.wal = Some(wal);
+        self
+    }
+
+    /// Format of the plan, `text` by default.
+    pub fn format(mut self, format: ExplainFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+}
+
+impl From<ExplainOptions> for JsValue {
+    fn from(options: ExplainOptions) -> Self {
+        let object = Object::new();
+        let flags = [
+            ("analyze", options.analyze),
+            ("verbose", options.verbose),
+            ("settings", options.settings),
+            ("buffers", options.buffers),
+            ("wal", options.wal),
+        ];
+        for (key, value) in flags {
+            if let Some(value) = value {
+                set(&object, key, &JsValue::from_bool(value));
+            }
+        }
+        if let Some(format) = options.format {
+            let format = match format {
+                ExplainFormat::Text => "text",
+                ExplainFormat::Json => "json",
+            };
+            set(&object, "format", &JsValue::from_str(format));
+        }
+        object.into()
+    }
+}
+
+/// Options to order the query
+#[derive(Debug, Clone, Default)]
+pub struct OrderOptions {
+    ascending: Option<bool>,
+    nulls_first: Option<bool>,
+    referenced_table: Option<String>,
+    foreign_table: Option<String>,
+}
+
+impl OrderOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Order ascending, `true` by default.
+    pub fn ascending(mut self, ascending: bool) -> Self {
+        self.ascending = Some(ascending);
+        self
+    }
+
+    /// Put `null` values first, by default they come last in ascending order.
+    pub fn nulls_first(mut self, nulls_first: bool) -> Self {
+        self.nulls_first = Some(nulls_first);
+        self
+    }
+
+    /// Order the rows of an embedded resource instead of the parent table.
+    pub fn referenced_table(mut self, table: &str) -> Self {
+        self.referenced_table = Some(table.to_string());
+        self
+    }
+
+    /// Same as [`OrderOptions::referenced_table`] for supabase-js before 2.32.
+    pub fn foreign_table(mut self, table: &str) -> Self {
+        self.foreign_table = Some(table.to_string());
+        self
+    }
+}
+
+impl From<OrderOptions> for JsValue {
+    fn from(options: OrderOptions) -> Self {
+        let object = Object::new();
+        if let Some(ascending) = options.ascending {
+            set(&object, "ascending", &JsValue::from_bool(ascending));
+        }
+        if let Some(nulls_first) = options.nulls_first {
+            set(&object, "nullsFirst", &JsValue::from_bool(nulls_first));
+        }
+        set_tables(&object, options.referenced_table, options.foreign_table);
+        object.into()
+    }
+}
+
+/// Options to limit the query
+#[derive(Debug, Clone, Default)]
+pub struct LimitOptions {
+    referenced_table: Option<String>,
+    foreign_table: Option<String>,
+}
+
+impl LimitOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the rows of an embedded resource instead of the parent table.
+    pub fn referenced_table(mut self, table: &str) -> Self {
+        self.referenced_table = Some(table.to_string());
+        self
+    }
+
+    /// Same as [`LimitOptions::referenced_table`] for supabase-js before 2.32.
+    pub fn foreign_table(mut self, table: &str) -> Self {
+        self.foreign_table = Some(table.to_string());
+        self
+    }
+}
+
+impl From<LimitOptions> for JsValue {
+    fn from(options: LimitOptions) -> Self {
+        let object = Object::new();
+        set_tables(&object, options.referenced_table, options.foreign_table);
+        object.into()
+    }
+}
+
+/// Options to limit the query to a range
+#[derive(Debug, Clone, Default)]
+pub struct RangeOptions {
+    referenced_table: Option<String>,
+    foreign_table: Option<String>,
+}
+
+impl RangeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit the rows of an embedded resource instead of the parent table.
+    pub fn referenced_table(mut self, table: &str) -> Self {
+        self.referenced_table = Some(table.to_string());
+        self
+    }
+
+    /// Same as [`RangeOptions::referenced_table`] for supabase-js before 2.32.
+    pub fn foreign_table(mut self, table: &str) -> Self {
+        self.foreign_table = Some(table.to_string());
+        self
+    }
+}
+
+impl From<RangeOptions> for JsValue {
+    fn from(options: RangeOptions) -> Self {
+        let object = Object::new();
+        set_tables(&object, options.referenced_table, options.foreign_table);
+        object.into()
+    }
+}
+
+fn set_tables(object: &Object, referenced_table: Option<String>, foreign_table: Option<String>) {
+    if let Some(table) = referenced_table {
+        set(object, "referencedTable", &JsValue::from_str(&table));
+    }
+    if let Some(table) = foreign_table {
+        set(object, "foreignTable", &JsValue::from_str(&table));
+    }
+}
+
+/// Options to insert data
+#[derive(Debug, Clone, Default)]
+pub struct InsertOptions {
+    count: Option<Count>,
+    default_to_null: Option<bool>,
+}
+
+impl InsertOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count inserted rows.
+    pub fn count(mut self, count: Count) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Fill columns missing from some of the rows with `null`, `true` by default.
+    /// With `false` they get the column default instead.
+    pub fn default_to_null(mut self, default_to_null: bool) -> Self {
+        self.default_to_null = Some(default_to_null);
+        self
+    }
+}
+
+impl From<InsertOptions> for JsValue {
+    fn from(options: InsertOptions) -> Self {
+        let object = Object::new();
+        set_count(&object, options.count);
+        if let Some(default_to_null) = options.default_to_null {
+            set(
+                &object,
+                "defaultToNull",
+                &JsValue::from_bool(default_to_null),
+            );
+        }
+        object.into()
+    }
+}
+
+/// Options to upsert data
+#[derive(Debug, Clone, Default)]
+pub struct UpsertOptions {
+    on_conflict: Option<String>,
+    ignore_duplicates: Option<bool>,
+    count: Option<Count>,
+    default_to_null: Option<bool>,
+}
+
+impl UpsertOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Comma-separated columns of a unique constraint to detect duplicates,
+    /// the primary key by default.
+    pub fn on_conflict(mut self, columns: &str) -> Self {
+        self.on_conflict = Some(columns.to_string());
+        self
+    }
+
+    /// Skip duplicate rows instead of merging them.
+    pub fn ignore_duplicates(mut self, ignore_duplicates: bool) -> Self {
+        self.ignore_duplicates = Some(ignore_duplicates);
+        self
+    }
+
+    /// Count upserted rows.
+    pub fn count(mut self, count: Count) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Fill columns missing from some of the rows with `null`, `true` by default.
+    /// With `false` they get the column default instead.
+    pub fn default_to_null(mut self, default_to_null: bool) -> Self {
+        self.default_to_null = Some(default_to_null);
+        self
+    }
+}
+
+impl From<UpsertOptions> for JsValue {
+    fn from(options: UpsertOptions) -> Self {
+        let object = Object::new();
+        if let Some(on_conflict) = &options.on_conflict {
+            set(&object, "onConflict", &JsValue::from_str(on_conflict));
+        }
+        if let Some(ignore_duplicates) = options.ignore_duplicates {
+            set(
+                &object,
+                "ignoreDuplicates",
+                &JsValue::from_bool(ignore_duplicates),
+            );
+        }
+        set_count(&object, options.count);
+        if let Some(default_to_null) = options.default_to_null {
+            set(
+                &object,
+                "defaultToNull",
+                &JsValue::from_bool(default_to_null),
+            );
+        }
+        object.into()
+    }
+}
+
+/// Options to update data
+#[derive(Debug, Clone, Default)]
+pub struct UpdateOptions {
+    count: Option<Count>,
+}
+
+impl UpdateOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count updated rows.
+    pub fn count(mut self, count: Count) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
+
+impl From<UpdateOptions> for JsValue {
+    fn from(options: UpdateOptions) -> Self {
+        let object = Object::new();
+        set_count(&object, options.count);
+        object.into()
+    }
+}
+
+/// Options to delete data
+#[derive(Debug, Clone, Default)]
+pub struct DeleteOptions {
+    count: Option<Count>,
+}
+
+impl DeleteOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count deleted rows.
+    pub fn count(mut self, count: Count) -> Self {
+        self.count = Some(count);
+        self
+    }
+}
+
+impl From<DeleteOptions> for JsValue {
+    fn from(options: DeleteOptions) -> Self {
+        let object = Object::new();
+        set_count(&object, options.count);
+        object.into()
+    }
+}
+
+fn set_count(object: &Object, count: Option<Count>) {
+    if let Some(count) = count {
+        set(object, "count", &JsValue::from_str(count.as_str()));
+    }
+}
+
+/// Query with the response type set by [`Database::returns`]
+///
+/// Await it to execute the query and deserialize `data` into `T`.
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct Returns<T> {
+    query: Database,
+    _type: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: DeserializeOwned> Returns<T> {
+    /// Query builder, e.g. to pass it on to supabase-js.
+    pub fn into_inner(self) -> Database {
+        self.query
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: DeserializeOwned + 'static> IntoFuture for Returns<T> {
+    type Output = Result<T, SupabaseError>;
+    type IntoFuture = Pin<Box<dyn Future<Output = Self::Output>>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move { self.query.execute_as().await })
+    }
+}
+
+/// Query result with the row count
+///
+/// `count` is only set when it was requested with [`SelectOptions::count`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResponse<T = JsValue> {
+    pub data: T,
+    pub count: Option<u64>,
+}
+
+/// Awaiting variants of the `Database` builders
+///
+/// Every variant awaits the builder with [`resolve`], so dropping the future aborts the request.
+macro_rules! awaiting {
+    ($($(#[$meta:meta])* fn $name:ident($($arg:ident: $ty:ty),*) => $builder:ident;)*) => {
+        impl Database {
+            $(
+                $(#[$meta])*
+                pub async fn $name(&self, $($arg: $ty),*) -> Result<JsValue, JsValue> {
+                    resolve(&self.$builder($($arg),*)).await
+                }
+            )*
+        }
+    };
+}
+
+impl SupabaseClient {
+    /// # Call a Postgres function
+    ///
+    /// Perform a function call. Set-returning functions can be combined with filters and modifiers.
+    ///
+    /// ```ignore
+    /// let args = js_sys::Object::new();
+    /// js_sys::Reflect::set(&args, &"name".into(), &"Albania".into()).unwrap();
+    /// let res = client
+    ///     .rpc_("search_countries", args.clone().into(), None)
+    ///     .order_("name", JsValue::UNDEFINED)
+    ///     .limit(10)
+    ///     .await;
+    ///
+    /// let res = client
+    ///     .rpc_("search_countries", args.into(), Some(RpcOptions::new().count(Count::Exact)))
+    ///     .execute_with_count()
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn rpc(
+        &self,
+        function: &str,
+        args: JsValue,
+        options: Option<RpcOptions>,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&self.rpc_(function, args, options)).await
+    }
+    pub fn rpc_(&self, function: &str, args: JsValue, options: Option<RpcOptions>) -> Database {
+        self.rpc_js(
+            function,
+            args,
+            options.map_or(JsValue::UNDEFINED, JsValue::from),
+        )
+    }
+}
+
+awaiting! {
+    /// # Fetch data
+    ///
+    /// Perform a SELECT query on the table or view, `*` by default.
+    ///
+    fn select(columns: Option<&str>) => select_;
+
+    /// # Order the query
+    ///
+    /// Order query result by column.
+    ///
+    /// ```ignore
+    /// let data: JsValue = client
+    /// .get()
+    /// .from("countries")
+    /// .select_(Some("name, cities ( name )"))
+    /// .order(
+    ///    "name",
+    ///    OrderOptions::new()
+    ///     .referenced_table("cities")
+    ///     .nulls_first(false)
+    ///     .ascending(true)
+    ///     .into(),
+    /// )
+    /// .await.unwrap();
+    /// ```
+    ///
+    fn order(column: &str, options: JsValue) => order_;
+
+    /// # Limit the query
+    ///
+    /// Limit the query result by count.
+    ///
+    fn limit(count: u32) => limit_;
+
+    /// # Limit the query to a range
+    ///
+    /// Limit the query result by from and to inclusively.
+    ///
+    fn range(from: u32, to: u32) => range_;
+
+    /// # Retrieve the query as one row
+    ///
+    /// Return data as a single object instead of an array of objects.
+    ///
+    fn single() => single_;
+
+    /// # Retrieve the query as 0-1 rows
+    ///
+    /// Return data as a single object instead of an array of objects.
+    ///
+    fn maybe_single() => maybe_single_;
+
+    /// # Retrieve the query as a CSV string
+    ///
+    /// Return data as a string in CSV format.
+    ///
+    /// ```ignore
+    /// let csv = client.get().from("countries").select_(Some("*")).csv().await.unwrap();
+    /// ```
+    ///
+    fn csv() => csv_;
+
+    /// # Retrieve the query as a GeoJSON object
+    ///
+    /// Return data as a GeoJSON `FeatureCollection`, requires PostGIS.
+    ///
+    /// ```ignore
+    /// let features = client.from("places").select_(Some("name, location")).geojson().await;
+    /// ```
+    ///
+    fn geojson() => geojson_;
+
+    /// # Set an abort signal
+    ///
+    /// Abort the request when the signal is aborted, e.g. on navigation.
+    ///
+    /// ```ignore
+    /// let controller = web_sys::AbortController::new().unwrap();
+    /// let res = client
+    ///     .from("countries")
+    ///     .select_(None)
+    ///     .abort_signal(&controller.signal())
+    ///     .await;
+    /// ```
+    ///
+    fn abort_signal(signal: &web_sys::AbortSignal) => abort_signal_;
+
+    /// # Throw on error
+    ///
+    /// Reject with the PostgREST error instead of resolving with `{ data, error }`,
+    /// so the error is returned as `Err`.
+    ///
+    /// ```ignore
+    /// let res: Result<JsValue, JsValue> = client.from("countries").select_(None).throw_on_error().await;
+    /// ```
+    ///
+    fn throw_on_error() => throw_on_error_;
+
+    /// # Column is equal to a value
+    ///
+    /// Match only rows where column is equal to value.
+    ///
+    fn eq(column: &str, value: &JsValue) => eq_;
+
+    /// # Column is not equal to a value
+    ///
+    /// Match only rows where column is not equal to value.
+    ///
+    fn neq(column: &str, value: &JsValue) => neq_;
+
+    /// # Column is greater than a value
+    ///
+    /// Match only rows where column is greater than value.
+    ///
+    fn gt(column: &str, value: &JsValue) => gt_;
+
+    /// # Column is greater than or equal to a value
+    ///
+    /// Match only rows where column is greater than or equal to value.
+    ///
+    fn gte(column: &str, value: &JsValue) => gte_;
+
+    /// # Column is less than a value
+    ///
+    /// Match only rows where column is less than value.
+    ///
+    fn lt(column: &str, value: &JsValue) => lt_;
+
+    /// # Column is less than or equal to a value
+    ///
+    /// Match only rows where column is less than or equal to value.
+    ///
+    fn lte(column: &str, value: &JsValue) => lte_;
+
+    /// # Column matches a pattern
+    ///
+    /// Match only rows where column matches pattern case-sensitively.
+    ///
+    fn like(column: &str, pattern: &str) => like_;
+
+    /// # Column matches a case-insensitive pattern
+    ///
+    /// Match only rows where column matches pattern case-insensitively.
+    ///
+    /// ```ignore
+    /// client.from("countries").select(None).ilike(&"name", &"%alba%").await;
+    /// ```
+    ///
+    fn ilike(column: &str, pattern: &str) => ilike_;
+
+    /// # Column is a value
+    ///
+    /// Match only rows where column IS value.
+    ///
+    /// ```ignore
+    /// // check for nullness
+    /// client.from("countries").select(None).is("name", JsValue::NULL);
+    /// // or check for true of false
+    /// client.from("countries").select(None).is("name", JsValue::TRUE);
+    /// ```
+    ///
+    fn is(column: &str, value: &JsValue) => is_;
+
+    /// # Column is in an array
+    ///
+    /// Match only rows where column is included in the values array.
+    ///
+    fn r#in(column: &str, values: Vec<JsValue>) => r#in_;
+
+    /// # Column contains every element in a value
+    ///
+    /// Only relevant for jsonb, array, and range columns. Match only rows where column contains every element appearing in value.
+    ///
+    fn contains(column: &str, value: JsValue) => contains_;
+
+    /// # Contained by value
+    ///
+    /// Only relevant for jsonb, array, and range columns. Match only rows where every element appearing in column is contained by value.
+    ///
+    fn contained_by(column: &str, value: JsValue) => contained_by_;
+
+    /// # Greater than a range
+    ///
+    /// Only relevant for range columns. Match only rows where every element in column is greater than any element in range.
+    ///
+    fn range_gt(column: &str, range: &str) => range_gt_;
+
+    /// # Greater than or equal to a range
+    ///
+    /// Only relevant for range columns. Match only rows where every element in column is either contained in range or greater than any element in range.
+    ///
+    fn range_gte(column: &str, range: &str) => range_gte_;
+
+    /// # Less than a range
+    ///
+    /// Only relevant for range columns. Match only rows where every element in column is less than any element in range.
+    ///
+    fn range_lt(column: &str, range: &str) => range_lt_;
+
+    /// # Less than or equal to a range
+    ///
+    /// Only relevant for range columns. Match only rows where every element in column is either contained in range or less than any element in range.
+    ///
+    fn range_lte(column: &str, range: &str) => range_lte_;
+
+    /// # Mutually exclusive to a range
+    ///
+    /// Only relevant for range columns. Match only rows where column is mutually exclusive to range and there can be no element between the two ranges.
+    ///
+    fn range_adjacent(column: &str, range: &str) => range_adjacent_;
+
+    /// # With a common element
+    ///
+    /// Only relevant for array and range columns. Match only rows where column and value have an element in common.
+    ///
+    fn overlaps(column: &str, value: JsValue) => overlaps_;
+
+    /// # Match a string
+    ///
+    /// Only relevant for text and tsvector columns. Match only rows where column matches the query string in query.
+    ///
+    fn text_search(column: &str, query: &str, options: JsValue) => text_search_;
+
+    /// # Don't match the filter
+    ///
+    /// Match only rows which don't satisfy the filter. `value` is used as-is in the PostgREST syntax.
+    ///
+    /// ```ignore
+    /// client.from("countries").select_(None).not("name", "is", &JsValue::NULL).await;
+    /// ```
+    ///
+    fn not(column: &str, operator: &str, value: &JsValue) => not_;
+
+    /// # Match at least one filter
+    ///
+    /// Match only rows which satisfy at least one of the filters in the PostgREST syntax.
+    /// Pass `{ foreignTable }` as options to filter an embedded resource. See [`Expr`](crate::Expr) to
+    /// build filters with proper quoting.
+    ///
+    /// ```ignore
+    /// client.from("countries").select_(None).or("id.eq.2,name.eq.Algeria", JsValue::UNDEFINED).await;
+    /// ```
+    ///
+    fn or(filters: &str, options: JsValue) => or_;
+
+    /// # Match the query
+    ///
+    /// Match only rows where each column in the object is equal to its value.
+    ///
+    fn r#match(query: JsValue) => r#match_;
+
+    /// # Match the filter
+    ///
+    /// Match only rows which satisfy the filter. `value` is used as-is in the PostgREST syntax,
+    /// e.g. `(1,2)` for the `in` operator.
+    ///
+    fn filter(column: &str, operator: &str, value: &JsValue) => filter_;
+
+    /// # Update data
+    ///
+    /// Perform an UPDATE on the table or view.
+    ///
+    fn update(values: &JsValue) => update_;
+
+    /// # Upsert data
+    ///
+    /// Perform an UPSERT on the table or view.
+    ///
+    fn upsert(values: JsValue) => upsert_;
+
+    /// # Insert data
+    ///
+    /// Perform an INSERT into the table or view.
+    ///
+    fn insert(values: JsValue) => insert_;
+}
+
+impl Database {
+    /// # Execute the query
+    ///
+    /// Await the query builder and unpack the response into `data` or a [`SupabaseError`].
+    ///
+    /// ```ignore
+    /// let res: Result<JsValue, SupabaseError> = client
+    ///     .from("countries")
+    ///     .delete_()
+    ///     .eq_("id", &JsValue::from(1))
+    ///     .execute()
+    ///     .await;
+    /// ```
+    ///
+    pub async fn execute(&self) -> Result<JsValue, SupabaseError> {
+        resolve(self).await.into_data()
+    }
+
+    /// # Execute the query with the row count
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .from("countries")
+    ///     .select_with_options_(None, SelectOptions::new().count(Count::Exact))
+    ///     .range_(0, 9)
+    ///     .execute_with_count()
+    ///     .await?;
+    /// let pages = res.count.unwrap_or(0).div_ceil(10);
+    /// ```
+    ///
+    pub async fn execute_with_count(&self) -> Result<QueryResponse, SupabaseError> {
+        let response = resolve(self).await.map_err(SupabaseError::from_js)?;
+        let count = get(&response, "count").as_f64().map(|count| count as u64);
+        let data = Ok(response).into_data()?;
+        Ok(QueryResponse { data, count })
+    }
+
+    /// # Fetch data with options
+    ///
+    /// Count rows or only fetch the count with `head`.
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .from("countries")
+    ///     .select_with_options(None, SelectOptions::new().count(Count::Exact).head(true))
+    ///     .await;
+    /// ```
+    ///
+    pub async fn select_with_options(
+        &self,
+        columns: Option<&str>,
+        options: SelectOptions,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&self.select_with_options_(columns, options)).await
+    }
+    pub fn select_with_options_(&self, columns: Option<&str>, options: SelectOptions) -> Database {
+        self.select_with_options_js(columns, options.into())
+    }
+
+    /// # Limit the query with options
+    ///
+    /// Limit the rows of the parent table or of an embedded resource.
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .from("countries")
+    ///     .select_(Some("name, cities ( name )"))
+    ///     .limit_with_options(1, LimitOptions::new().referenced_table("cities"))
+    ///     .await;
+    /// ```
+    ///
+    pub async fn limit_with_options(
+        &self,
+        count: u32,
+        options: LimitOptions,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&self.limit_with_options_(count, options)).await
+    }
+    pub fn limit_with_options_(&self, count: u32, options: LimitOptions) -> Database {
+        self.limit_with_options_js(count, options.into())
+    }
+
+    /// # Limit the query to a range with options
+    ///
+    /// Limit the rows of the parent table or of an embedded resource by from and to inclusively.
+    ///
+    pub async fn range_with_options(
+        &self,
+        from: u32,
+        to: u32,
+        options: RangeOptions,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&self.range_with_options_(from, to, options)).await
+    }
+    pub fn range_with_options_(&self, from: u32, to: u32, options: RangeOptions) -> Database {
+        self.range_with_options_js(from, to, options.into())
+    }
+
+    /// # Insert data with options
+    ///
+    pub async fn insert_with_options(
+        &self,
+        values: JsValue,
+        options: InsertOptions,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&self.insert_with_options_(values, options)).await
+    }
+    pub fn insert_with_options_(&self, values: JsValue, options: InsertOptions) -> Database {
+        self.insert_with_options_js(values, options.into())
+    }
+
+    /// # Upsert data with options
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .from("devices")
+    ///     .upsert_with_options(
+    ///         rows,
+    ///         UpsertOptions::new().on_conflict("user_id, name").ignore_duplicates(true),
+    ///     )
+    ///     .await;
+    /// ```
+    ///
+    pub async fn upsert_with_options(
+        &self,
+        values: JsValue,
+        options: UpsertOptions,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&self.upsert_with_options_(values, options)).await
+    }
+    pub fn upsert_with_options_(&self, values: JsValue, options: UpsertOptions) -> Database {
+        self.upsert_with_options_js(values, options.into())
+    }
+
+    /// # Update data with options
+    ///
+    /// Combine the builder variant with filters.
+    ///
+    pub async fn update_with_options(
+        &self,
+        values: &JsValue,
+        options: UpdateOptions,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&self.update_with_options_(values, options)).await
+    }
+    pub fn update_with_options_(&self, values: &JsValue, options: UpdateOptions) -> Database {
+        self.update_with_options_js(values, options.into())
+    }
+
+    /// # Delete data
+    ///
+    /// Perform a DELETE filtered by `filter`, which should always add filters.
+    /// Use the builder variant `delete_` to chain filters yourself.
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .from("countries")
+    ///     .delete(|query| query.eq_("id", &JsValue::from(1)))
+    ///     .await;
+    /// ```
+    ///
+    pub async fn delete(
+        &self,
+        filter: impl FnOnce(Database) -> Database,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&filter(self.delete_())).await
+    }
+
+    /// # Delete data with options
+    ///
+    /// Perform a DELETE filtered by `filter`. Combine the builder variant with filters.
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .from("countries")
+    ///     .delete_with_options_(DeleteOptions::new().count(Count::Exact))
+    ///     .eq_("continent", &JsValue::from("Antarctica"))
+    ///     .execute_with_count()
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn delete_with_options(
+        &self,
+        options: DeleteOptions,
+        filter: impl FnOnce(Database) -> Database,
+    ) -> Result<JsValue, JsValue> {
+        resolve(&filter(self.delete_with_options_(options))).await
+    }
+    pub fn delete_with_options_(&self, options: DeleteOptions) -> Database {
+        self.delete_with_options_js(options.into())
+    }
+
+    /// # Using explain
+    ///
+    /// Return the Postgres execution plan of the query instead of the data.
+    ///
+    /// ```ignore
+    /// let plan = client
+    ///     .from("countries")
+    ///     .select_(None)
+    ///     .explain(ExplainOptions::new().analyze(true).verbose(true))
+    ///     .await;
+    /// ```
+    ///
+    pub async fn explain(&self, options: ExplainOptions) -> Result<JsValue, JsValue> {
+        resolve(&self.explain_(options)).await
+    }
+    pub fn explain_(&self, options: ExplainOptions) -> Database {
+        self.explain_js(options.into())
+    }
+
+    /// # Delete data and count rows
+    ///
+    /// Perform a DELETE filtered by `filter` and return only the number of deleted rows.
+    ///
+    /// ```ignore
+    /// let deleted: u64 = client
+    ///     .from("countries")
+    ///     .delete_count(Count::Exact, |query| query.eq_("continent", &"Antarctica".into()))
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn delete_count(
+        &self,
+        count: Count,
+        filter: impl FnOnce(Database) -> Database,
+    ) -> Result<u64, SupabaseError> {
+        let query = filter(self.delete_with_options_(DeleteOptions::new().count(count)));
+        query.execute_count().await
+    }
+
+    /// Execute a mutation without representation and return its row count.
+    pub(crate) async fn execute_count(&self) -> Result<u64, SupabaseError> {
+        Ok(self.execute_with_count().await?.count.unwrap_or(0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Database {
+    /// # Execute the query as `T`
+    ///
+    /// Await the query builder and deserialize `data` into `T`.
+    ///
+    pub async fn execute_as<T: DeserializeOwned>(&self) -> Result<T, SupabaseError> {
+        from_value(self.execute().await?)
+    }
+
+    /// # Execute the query with the row count as `T`
+    ///
+    /// With `head`, `data` is null, so deserialize into an `Option`.
+    ///
+    pub async fn execute_with_count_as<T: DeserializeOwned>(
+        &self,
+    ) -> Result<QueryResponse<T>, SupabaseError> {
+        let QueryResponse { data, count } = self.execute_with_count().await?;
+        Ok(QueryResponse {
+            data: from_value(data)?,
+            count,
+        })
+    }
+
+    /// # Override the response type
+    ///
+    /// Set the type `data` deserializes into when the query is awaited.
+    ///
+    /// ```ignore
+    /// let countries: Vec<Country> = client
+    ///     .from("countries")
+    ///     .select_(None)
+    ///     .returns::<Vec<Country>>()
+    ///     .await?;
+    /// ```
+    ///
+    pub fn returns<T: DeserializeOwned>(&self) -> Returns<T> {
+        Returns {
+            query: self.clone(),
+            _type: PhantomData,
+        }
+    }
+
+    /// # Fetch data as `Vec<T>`
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct Country {
+    ///     id: i64,
+    ///     name: String,
+    /// }
+    /// let countries: Vec<Country> = client.from("countries").select_as(None).await?;
+    /// ```
+    ///
+    pub async fn select_as<T: DeserializeOwned>(
+        &self,
+        columns: Option<&str>,
+    ) -> Result<Vec<T>, SupabaseError> {
+        self.select_(columns).execute_as().await
+    }
+
+    /// # Retrieve the query as one row of `T`
+    ///
+    /// ```ignore
+    /// let country: Country = client
+    ///     .from("countries")
+    ///     .select_(None)
+    ///     .eq_("id", &JsValue::from(1))
+    ///     .single_as()
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn single_as<T: DeserializeOwned>(&self) -> Result<T, SupabaseError> {
+        self.single_().execute_as().await
+    }
+
+    /// # Retrieve the query as 0-1 rows of `T`
+    ///
+    pub async fn maybe_single_as<T: DeserializeOwned>(&self) -> Result<Option<T>, SupabaseError> {
+        self.maybe_single_().execute_as().await
+    }
+
+    /// # Insert serialized data
+    ///
+    /// Serialize `values`, a row or a slice of rows, and perform an INSERT.
+    ///
+    pub async fn insert_as<T: Serialize + ?Sized>(&self, values: &T) -> Result<(), SupabaseError> {
+        self.insert_as_(values)?.execute().await.map(|_| ())
+    }
+    pub fn insert_as_<T: Serialize + ?Sized>(&self, values: &T) -> Result<Database, SupabaseError> {
+        Ok(self.insert_(to_value(values)?))
+    }
+
+    /// # Update serialized data
+    ///
+    /// Serialize `values` and perform an UPDATE. Combine the builder variant with filters.
+    ///
+    /// ```ignore
+    /// client
+    ///     .from("countries")
+    ///     .update_as_(&CountryName { name: "Australia".into() })?
+    ///     .eq_("id", &JsValue::from(1))
+    ///     .execute()
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn update_as<T: Serialize + ?Sized>(&self, values: &T) -> Result<(), SupabaseError> {
+        self.update_as_(values)?.execute().await.map(|_| ())
+    }
+    pub fn update_as_<T: Serialize + ?Sized>(&self, values: &T) -> Result<Database, SupabaseError> {
+        Ok(self.update_(&to_value(values)?))
+    }
+
+    /// # Upsert serialized data
+    ///
+    /// Serialize `values`, a row or a slice of rows, and perform an UPSERT.
+    ///
+    pub async fn upsert_as<T: Serialize + ?Sized>(&self, values: &T) -> Result<(), SupabaseError> {
+        self.upsert_as_(values)?.execute().await.map(|_| ())
+    }
+    pub fn upsert_as_<T: Serialize + ?Sized>(&self, values: &T) -> Result<Database, SupabaseError> {
+        Ok(self.upsert_(to_value(values)?))
+    }
+
+    /// # Retrieve the affected rows as `Vec<T>`
+    ///
+    /// Return the rows inserted, updated or deleted by the mutation. Call it after filters.
+    ///
+    /// ```ignore
+    /// let deleted: Vec<Country> = client
+    ///     .from("countries")
+    ///     .delete_()
+    ///     .eq_("id", &JsValue::from(1))
+    ///     .returning()
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn returning<T: DeserializeOwned>(&self) -> Result<Vec<T>, SupabaseError> {
+        self.select_(None).execute_as().await
+    }
+
+    /// # Insert serialized data and return the rows as `Vec<T>`
+    ///
+    /// ```ignore
+    /// let inserted: Vec<Country> = client
+    ///     .from("countries")
+    ///     .insert_returning(&NewCountry { name: "Denmark".into() })
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn insert_returning<T: DeserializeOwned, V: Serialize + ?Sized>(
+        &self,
+        values: &V,
+    ) -> Result<Vec<T>, SupabaseError> {
+        self.insert_as_(values)?.returning().await
+    }
+
+    /// # Upsert serialized data and return the rows as `Vec<T>`
+    ///
+    pub async fn upsert_returning<T: DeserializeOwned, V: Serialize + ?Sized>(
+        &self,
+        values: &V,
+    ) -> Result<Vec<T>, SupabaseError> {
+        self.upsert_as_(values)?.returning().await
+    }
+
+    /// # Update serialized data and return the rows as `Vec<T>`
+    ///
+    /// Perform an UPDATE filtered by `filter` and return the updated rows.
+    ///
+    /// ```ignore
+    /// let updated: Vec<Country> = client
+    ///     .from("countries")
+    ///     .update_returning(&CountryName { name: "Australia".into() }, |query| {
+    ///         query.eq_("id", &JsValue::from(1))
+    ///     })
+    ///     .await?;
+    /// ```
+    ///
+    pub async fn update_returning<T: DeserializeOwned, V: Serialize + ?Sized>(
+        &self,
+        values: &V,
+        filter: impl FnOnce(Database) -> Database,
+    ) -> Result<Vec<T>, SupabaseError> {
+        filter(self.update_as_(values)?).returning().await
+    }
+
+    /// # Delete data and return the rows as `Vec<T>`
+    ///
+    /// Perform a DELETE filtered by `filter` and return the deleted rows.
+    ///
+    pub async fn delete_returning<T: DeserializeOwned>(
+        &self,
+        filter: impl FnOnce(Database) -> Database,
+    ) -> Result<Vec<T>, SupabaseError> {
+        filter(self.delete_()).returning().await
+    }
+
+    /// # Insert serialized data and count rows
+    ///
+    /// Return only the number of inserted rows.
+    ///
+    pub async fn insert_count<V: Serialize + ?Sized>(
+        &self,
+        values: &V,
+        count: Count,
+    ) -> Result<u64, SupabaseError> {
+        let options = InsertOptions::new().count(count);
+        self.insert_with_options_(to_value(values)?, options)
+            .execute_count()
+            .await
+    }
+
+    /// # Upsert serialized data and count rows
+    ///
+    /// Return only the number of upserted rows.
+    ///
+    pub async fn upsert_count<V: Serialize + ?Sized>(
+        &self,
+        values: &V,
+        count: Count,
+    ) -> Result<u64, SupabaseError> {
+        let options = UpsertOptions::new().count(count);
+        self.upsert_with_options_(to_value(values)?, options)
+            .execute_count()
+            .await
+    }
+
+    /// # Update serialized data and count rows
+    ///
+    /// Perform an UPDATE filtered by `filter` and return only the number of updated rows.
+    ///
+    pub async fn update_count<V: Serialize + ?Sized>(
+        &self,
+        values: &V,
+        count: Count,
+        filter: impl FnOnce(Database) -> Database,
+    ) -> Result<u64, SupabaseError> {
+        let options = UpdateOptions::new().count(count);
+        filter(self.update_with_options_(&to_value(values)?, options))
+            .execute_count()
+            .await
+    }
+}
@@ -0,0 +1,297 @@
+//! Errors and helpers to unpack supabase-js responses.
+//!
+//! Most supabase-js calls resolve with a `{ data, error }` object instead of rejecting,
+//! so an `Ok` returned by a raw binding may still carry an error. [`IntoSupabaseResult`]
+//! turns those responses into a plain `Result<JsValue, SupabaseError>`.
+
+use std::fmt;
+
+use js_sys::{Function, Object, Promise, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::AbortController;
+
+use crate::Database;
+
+/// Error returned by Supabase services
+#[derive(Debug, Clone, PartialEq)]
+pub enum SupabaseError {
+    /// # PostgREST error
+    ///
+    /// Returned by database queries and Postgres functions.
+    Postgrest {
+        message: String,
+        code: String,
+        details: Option<String>,
+        hint: Option<String>,
+    },
+    /// # Auth error
+    ///
+    /// Returned by GoTrue, e.g. `AuthApiError` or `AuthSessionMissingError`.
+    Auth {
+        message: String,
+        status: Option<u16>,
+        name: String,
+    },
+    /// # Storage error
+    ///
+    /// Returned by the Storage API, e.g. `StorageApiError`.
+    Storage {
+        message: String,
+        status: Option<u16>,
+        name: String,
+    },
+    /// # Edge Function HTTP error
+    ///
+    /// The function returned a non-2xx status. `context` holds the `Response`.
+    FunctionsHttp { message: String, context: JsValue },
+    /// # Edge Function relay error
+    ///
+    /// The relay between the client and the function failed.
+    FunctionsRelay { message: String, context: JsValue },
+    /// # Edge Function fetch error
+    ///
+    /// The request to the function could not be sent.
+    FunctionsFetch { message: String, context: JsValue },
+    /// # JavaScript exception
+    ///
+    /// A rejected promise or a thrown exception, e.g. a failed `fetch`.
+    Js(JsValue),
+    /// # Serialization error
+    ///
+    /// A Rust value could not be converted into a JavaScript value.
+    Serialize(String),
+    /// # Deserialization error
+    ///
+    /// Response data does not match the requested Rust type.
+    Deserialize(String),
+    /// # Invalid filter
+    ///
+    /// A filter expression can't be sent to PostgREST, e.g. an empty `or` group.
+    InvalidFilter(String),
+}
+
+impl SupabaseError {
+    /// Classify a JavaScript error value returned by supabase-js
+    pub fn from_js(error: JsValue) -> Self {
+        let name = get_string(&error, "name").unwrap_or_default();
+        let message = get_string(&error, "message").unwrap_or_default();
+        if is_true(&error, "__isAuthError") || name.starts_with("Auth") {
+            return SupabaseError::Auth {
+                message,
+                status: get_u16(&error, "status"),
+                name,
+            };
+        }
+        if is_true(&error, "__isStorageError") || name.starts_with("Storage") {
+            return SupabaseError::Storage {
+                message,
+                status: get_u16(&error, "status").or_else(|| {
+                    get_string(&error, "statusCode").and_then(|code| code.parse().ok())
+                }),
+                name,
+            };
+        }
+        if name.starts_with("Functions") {
+            let context = get(&error, "context");
+            match name.as_str() {
+                "FunctionsHttpError" => return SupabaseError::FunctionsHttp { message, context },
+                "FunctionsRelayError" => return SupabaseError::FunctionsRelay { message, context },
+                "FunctionsFetchError" => return SupabaseError::FunctionsFetch { message, context },
+                _ => {}
+            }
+        }
+        if !error.is_instance_of::<js_sys::Error>() || name == "PostgrestError" {
+            if let Some(code) = get_string(&error, "code") {
+                return SupabaseError::Postgrest {
+                    message,
+                    code,
+                    details: get_string(&error, "details"),
+                    hint: get_string(&error, "hint"),
+                };
+            }
+        }
+        SupabaseError::Js(error)
+    }
+
+    /// Error message
+    pub fn message(&self) -> String {
+        match self {
+            SupabaseError::Postgrest { message, .. }
+            | SupabaseError::Auth { message, .. }
+            | SupabaseError::Storage { message, .. }
+            | SupabaseError::FunctionsHttp { message, .. }
+            | SupabaseError::FunctionsRelay { message, .. }
+            | SupabaseError::FunctionsFetch { message, .. }
+            | SupabaseError::Serialize(message)
+            | SupabaseError::Deserialize(message)
+            | SupabaseError::InvalidFilter(message) => message.clone(),
+            SupabaseError::Js(value) => get_string(value, "message")
+                .or_else(|| value.as_string())
+                .unwrap_or_else(|| format!("{:?}", value)),
+        }
+    }
+}
+
+impl fmt::Display for SupabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupabaseError::Postgrest { message, code, .. } => {
+                write!(f, "PostgREST error {}: {}", code, message)
+            }
+            SupabaseError::Auth { message, name, .. } => write!(f, "{}: {}", name, message),
+            SupabaseError::Storage { message, name, .. } => write!(f, "{}: {}", name, message),
+            SupabaseError::FunctionsHttp { message, .. } => {
+                write!(f, "FunctionsHttpError: {}", message)
+            }
+            SupabaseError::FunctionsRelay { message, .. } => {
+                write!(f, "FunctionsRelayError: {}", message)
+            }
+            SupabaseError::FunctionsFetch { message, .. } => {
+                write!(f, "FunctionsFetchError: {}", message)
+            }
+            SupabaseError::Js(_) => write!(f, "JavaScript error: {}", self.message()),
+            SupabaseError::Serialize(message) => write!(f, "serialization error: {}", message),
+            SupabaseError::Deserialize(message) => {
+                write!(f, "deserialization error: {}", message)
+            }
+            SupabaseError::InvalidFilter(message) => write!(f, "invalid filter: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SupabaseError {}
+
+impl From<JsValue> for SupabaseError {
+    fn from(error: JsValue) -> Self {
+        SupabaseError::from_js(error)
+    }
+}
+
+impl From<SupabaseError> for JsValue {
+    fn from(error: SupabaseError) -> Self {
+        match error {
+            SupabaseError::Js(value) => value,
+            error => js_sys::Error::new(&error.to_string()).into(),
+        }
+    }
+}
+
+/// Unpack `{ data, error }` responses
+///
+/// ```ignore
+/// use supabase_js_rs::IntoSupabaseResult;
+///
+/// let countries: JsValue = client.from("countries").select(None).await.into_data()?;
+/// ```
+pub trait IntoSupabaseResult: Sized {
+    /// Return `data` of the response, or the error it resolved or rejected with.
+    fn into_data(self) -> Result<JsValue, SupabaseError>;
+
+    /// Deserialize `data` of the response into `T`.
+    ///
+    /// ```ignore
+    /// let factors: Factors = client.auth().mfa().list_factors().await.into_data_as()?;
+    /// ```
+    #[cfg(feature = "serde")]
+    fn into_data_as<T: serde::de::DeserializeOwned>(self) -> Result<T, SupabaseError> {
+        from_value(self.into_data()?)
+    }
+}
+
+impl IntoSupabaseResult for Result<JsValue, JsValue> {
+    fn into_data(self) -> Result<JsValue, SupabaseError> {
+        let response = self.map_err(SupabaseError::from_js)?;
+        let error = get(&response, "error");
+        if !error.is_null() && !error.is_undefined() {
+            return Err(SupabaseError::from_js(error));
+        }
+        Ok(get(&response, "data"))
+    }
+}
+
+/// Convert a Rust value into a JSON-compatible JavaScript value.
+#[cfg(feature = "serde")]
+pub(crate) fn to_value<T: serde::Serialize + ?Sized>(value: &T) -> Result<JsValue, SupabaseError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|error| SupabaseError::Serialize(error.to_string()))
+}
+
+/// Convert a JavaScript value into a Rust value.
+#[cfg(feature = "serde")]
+pub(crate) fn from_value<T: serde::de::DeserializeOwned>(
+    value: JsValue,
+) -> Result<T, SupabaseError> {
+    serde_wasm_bindgen::from_value(value)
+        .map_err(|error| SupabaseError::Deserialize(error.to_string()))
+}
+
+/// Await a supabase-js builder, which is a thenable rather than a `Promise`.
+///
+/// A query builder gets an `AbortController` unless it already has a signal from
+/// `abort_signal`, and the request is aborted when the future is dropped before it resolves.
+pub(crate) async fn resolve(thenable: &JsValue) -> Result<JsValue, JsValue> {
+    let Some(query) = abortable(thenable) else {
+        return JsFuture::from(Promise::resolve(thenable)).await;
+    };
+    let controller = AbortController::new()?;
+    let query: JsValue = query.abort_signal_(&controller.signal()).into();
+    // `then` starts the fetch with the signal right away, so the signal can be cleared again:
+    // `abortSignal` sets it on the builder itself, which the caller may await again
+    let then: Function = get(&query, "then").unchecked_into();
+    let promise = Promise::new(&mut |resolve, reject| {
+        if let Err(error) = then.call2(&query, &resolve, &reject) {
+            let _ = reject.call1(&JsValue::UNDEFINED, &error);
+        }
+    });
+    set(query.unchecked_ref(), "signal", &JsValue::UNDEFINED);
+    let mut guard = AbortOnDrop(Some(controller));
+    let result = JsFuture::from(promise).await;
+    guard.0 = None;
+    result
+}
+
+/// Query builder without an abort signal of its own.
+fn abortable(thenable: &JsValue) -> Option<&Database> {
+    let signal = get(thenable, "signal");
+    if get(thenable, "abortSignal").is_function() && (signal.is_undefined() || signal.is_null()) {
+        Some(thenable.unchecked_ref())
+    } else {
+        None
+    }
+}
+
+/// Aborts a pending request when dropped.
+struct AbortOnDrop(Option<AbortController>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        if let Some(controller) = self.0.take() {
+            controller.abort();
+        }
+    }
+}
+
+pub(crate) fn get(target: &JsValue, key: &str) -> JsValue {
+    if !target.is_object() {
+        return JsValue::UNDEFINED;
+    }
+    Reflect::get(target, &JsValue::from_str(key)).unwrap_or(JsValue::UNDEFINED)
+}
+
+pub(crate) fn set(target: &Object, key: &str, value: &JsValue) {
+    Reflect::set(target, &JsValue::from_str(key), value).unwrap();
+}
+
+pub(crate) fn get_string(target: &JsValue, key: &str) -> Option<String> {
+    get(target, key).as_string()
+}
+
+fn get_u16(target: &JsValue, key: &str) -> Option<u16> {
+    get(target, key).as_f64().map(|status| status as u16)
+}
+
+fn is_true(target: &JsValue, key: &str) -> bool {
+    get(target, key).as_bool().unwrap_or(false)
+}
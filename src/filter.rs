@@ -0,0 +1,414 @@
+//! Expression DSL for logical filters in the PostgREST syntax.
+
+use std::fmt;
+
+use wasm_bindgen::JsValue;
+
+use crate::{Database, FilterValue, SupabaseError};
+
+/// Column of a filter expression
+///
+/// ```ignore
+/// use supabase_js_rs::{col, Expr};
+///
+/// let expr = Expr::or([
+///     col("name").eq("Algeria"),
+///     Expr::and([col("id").gt(10), col("continent").is(None)]),
+/// ]);
+/// ```
+pub fn col(name: &str) -> Column {
+    Column(name.to_string())
+}
+
+/// Column of a filter expression, see [`col`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column(String);
+
+impl Column {
+    /// Column is equal to value, or IS NULL for `None`.
+    pub fn eq(&self, value: impl Into<FilterValue>) -> Expr {
+        match value.into() {
+            FilterValue::Null => self.is(None),
+            value => self.condition("eq", Operand::Value(value)),
+        }
+    }
+
+    /// Column is not equal to value, or IS NOT NULL for `None`.
+    pub fn neq(&self, value: impl Into<FilterValue>) -> Expr {
+        match value.into() {
+            FilterValue::Null => self.is(None).not(),
+            value => self.condition("neq", Operand::Value(value)),
+        }
+    }
+
+    /// Column is greater than value.
+    pub fn gt(&self, value: impl Into<FilterValue>) -> Expr {
+        self.condition("gt", Operand::Value(value.into()))
+    }
+
+    /// Column is greater than or equal to value.
+    pub fn gte(&self, value: impl Into<FilterValue>) -> Expr {
+        self.condition("gte", Operand::Value(value.into()))
+    }
+
+    /// Column is less than value.
+    pub fn lt(&self, value: impl Into<FilterValue>) -> Expr {
+        self.condition("lt", Operand::Value(value.into()))
+    }
+
+    /// Column is less than or equal to value.
+    pub fn lte(&self, value: impl Into<FilterValue>) -> Expr {
+        self.condition("lte", Operand::Value(value.into()))
+    }
+
+    /// Column matches pattern case-sensitively.
+    pub fn like(&self, pattern: &str) -> Expr {
+        self.condition("like", Operand::Value(pattern.into()))
+    }
+
+    /// Column matches pattern case-insensitively.
+    pub fn ilike(&self, pattern: &str) -> Expr {
+        self.condition("ilike", Operand::Value(pattern.into()))
+    }
+
+    /// Column IS value, i.e. `None`, `true` or `false`.
+    pub fn is(&self, value: Option<bool>) -> Expr {
+        self.condition("is", Operand::Value(value.into()))
+    }
+
+    /// Column is included in the values.
+    pub fn r#in<T: Into<FilterValue>>(&self, values: impl IntoIterator<Item = T>) -> Expr {
+        let values = values.into_iter().map(Into::into).collect();
+        self.condition("in", Operand::List(values))
+    }
+
+    /// Column satisfies `operator` with a value in the PostgREST syntax, used as-is.
+    pub fn op(&self, operator: &str, value: &str) -> Expr {
+        self.condition(operator, Operand::Raw(value.to_string()))
+    }
+
+    fn condition(&self, operator: &str, value: Operand) -> Expr {
+        Expr::Condition {
+            column: self.0.clone(),
+            operator: operator.to_string(),
+            value,
+            negated: false,
+        }
+    }
+}
+
+/// Right-hand side of a condition
+///
+/// Values are double-quoted only where PostgREST parses quotes, i.e. inside `and`/`or`
+/// groups and `in` lists.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    /// Single value.
+    Value(FilterValue),
+    /// List of values, `(a,b)`.
+    List(Vec<FilterValue>),
+    /// Value in the PostgREST syntax, never quoted.
+    Raw(String),
+}
+
+impl Operand {
+    /// Render for a top-level filter, where PostgREST keeps quotes in the value.
+    fn render(&self) -> String {
+        match self {
+            Operand::Value(value) => render_value(value, false),
+            Operand::List(values) => render_list(values),
+            Operand::Raw(value) => value.clone(),
+        }
+    }
+
+    /// Render inside an `and`/`or` group.
+    fn render_quoted(&self) -> String {
+        match self {
+            Operand::Value(value) => render_value(value, true),
+            Operand::List(values) => render_list(values),
+            Operand::Raw(value) => value.clone(),
+        }
+    }
+}
+
+/// Logical filter expression
+///
+/// Renders into the PostgREST syntax with [`Display`](fmt::Display) and is applied to a query
+/// with [`Database::filter_by`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// `column.operator.value`, `column.not.operator.value` when negated.
+    Condition {
+        column: String,
+        operator: String,
+        value: Operand,
+        negated: bool,
+    },
+    /// Every expression is satisfied.
+    And(Vec<Expr>),
+    /// At least one expression is satisfied.
+    Or(Vec<Expr>),
+}
+
+impl Expr {
+    pub fn and(exprs: impl IntoIterator<Item = Expr>) -> Expr {
+        Expr::And(exprs.into_iter().collect())
+    }
+
+    pub fn or(exprs: impl IntoIterator<Item = Expr>) -> Expr {
+        Expr::Or(exprs.into_iter().collect())
+    }
+
+    /// Negate the expression, pushing the negation down to the conditions.
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Expr {
+        match self {
+            Expr::Condition {
+                column,
+                operator,
+                value,
+                negated,
+            } => Expr::Condition {
+                column,
+                operator,
+                value,
+                negated: !negated,
+            },
+            Expr::And(exprs) => Expr::Or(exprs.into_iter().map(Expr::not).collect()),
+            Expr::Or(exprs) => Expr::And(exprs.into_iter().map(Expr::not).collect()),
+        }
+    }
+
+    /// Fold empty groups into constants: an empty `and` always matches, an empty `or` never does.
+    fn fold(&self) -> Folded {
+        match self {
+            Expr::Condition { .. } => Folded::Expr(self.clone()),
+            Expr::And(exprs) => fold_group(exprs, true, Expr::And),
+            Expr::Or(exprs) => fold_group(exprs, false, Expr::Or),
+        }
+    }
+}
+
+/// Expression without empty groups
+#[derive(Debug, Clone, PartialEq)]
+enum Folded {
+    Always(bool),
+    Expr(Expr),
+}
+
+/// Fold a group whose empty value is `identity`, `true` for `and` and `false` for `or`.
+fn fold_group(exprs: &[Expr], identity: bool, group: fn(Vec<Expr>) -> Expr) -> Folded {
+    let mut kept = Vec::new();
+    for expr in exprs {
+        match expr.fold() {
+            Folded::Always(value) if value == identity => {}
+            Folded::Always(value) => return Folded::Always(value),
+            Folded::Expr(expr) => kept.push(expr),
+        }
+    }
+    match kept.len() {
+        0 => Folded::Always(identity),
+        1 => Folded::Expr(kept.remove(0)),
+        _ => Folded::Expr(group(kept)),
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Condition {
+                column,
+                operator,
+                value,
+                negated,
+            } => {
+                let not = if *negated { "not." } else { "" };
+                write!(
+                    f,
+                    "{}.{}{}.{}",
+                    column,
+                    not,
+                    operator,
+                    value.render_quoted()
+                )
+            }
+            Expr::And(exprs) => write!(f, "and({})", join(exprs)),
+            Expr::Or(exprs) => write!(f, "or({})", join(exprs)),
+        }
+    }
+}
+
+impl Database {
+    /// # Match the expression
+    ///
+    /// Lowers `expr` into `filter`, `not` and `or` calls. An empty `and` group matches every
+    /// row and adds no filter. An empty `or` group matches no row, which PostgREST can't
+    /// express, so it is returned as [`SupabaseError::InvalidFilter`].
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .from("countries")
+    ///     .select_(None)
+    ///     .filter_by(&Expr::or([col("id").eq(2), col("name").eq("Algeria")]))?
+    ///     .execute()
+    ///     .await;
+    /// ```
+    ///
+    pub fn filter_by(&self, expr: &Expr) -> Result<Database, SupabaseError> {
+        match expr.fold() {
+            Folded::Always(true) => Ok(self.clone()),
+            Folded::Always(false) => Err(SupabaseError::InvalidFilter(format!(
+                "`{}` never matches a row",
+                expr
+            ))),
+            Folded::Expr(expr) => Ok(self.lower(&expr)),
+        }
+    }
+
+    /// Lower an expression without empty groups.
+    fn lower(&self, expr: &Expr) -> Database {
+        match expr {
+            Expr::Condition {
+                column,
+                operator,
+                value,
+                negated,
+            } => {
+                let value = JsValue::from_str(&value.render());
+                if *negated {
+                    self.not_(column, operator, &value)
+                } else {
+                    self.filter_(column, operator, &value)
+                }
+            }
+            Expr::And(exprs) => exprs
+                .iter()
+                .fold(self.clone(), |query, expr| query.lower(expr)),
+            Expr::Or(exprs) => self.or_(&join(exprs), JsValue::UNDEFINED),
+        }
+    }
+}
+
+fn join(exprs: &[Expr]) -> String {
+    exprs
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn render_value(value: &FilterValue, quoted: bool) -> String {
+    match value {
+        FilterValue::Null => "null".to_string(),
+        FilterValue::Bool(value) => value.to_string(),
+        FilterValue::Int(value) => value.to_string(),
+        FilterValue::Float(value) => value.to_string(),
+        FilterValue::Text(value) if quoted => quote(value),
+        FilterValue::Text(value) => value.clone(),
+    }
+}
+
+fn render_list(values: &[FilterValue]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|value| render_value(value, true))
+        .collect();
+    format!("({})", values.join(","))
+}
+
+/// Double-quote values containing characters reserved by PostgREST.
+fn quote(value: &str) -> String {
+    let reserved =
+        |c: char| matches!(c, ',' | '.' | ':' | '(' | ')' | '"' | '\\') || c.is_whitespace();
+    if !value.is_empty() && !value.contains(reserved) {
+        return value.to_string();
+    }
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_reserved_characters() {
+        assert_eq!(quote("Algeria"), "Algeria");
+        assert_eq!(quote("a@b.com"), "\"a@b.com\"");
+        assert_eq!(quote("a,b"), "\"a,b\"");
+        assert_eq!(quote("12:30"), "\"12:30\"");
+        assert_eq!(quote("(x)"), "\"(x)\"");
+        assert_eq!(quote(" padded"), "\" padded\"");
+        assert_eq!(quote(""), "\"\"");
+    }
+
+    #[test]
+    fn escapes_quotes_and_backslashes() {
+        assert_eq!(quote("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote("a\\b"), "\"a\\\\b\"");
+    }
+
+    #[test]
+    fn keeps_top_level_values_unquoted() {
+        assert_eq!(Operand::Value("a@b.com".into()).render(), "a@b.com");
+        assert_eq!(Operand::Value("%.com".into()).render(), "%.com");
+        assert_eq!(Operand::Value(FilterValue::Null).render(), "null");
+    }
+
+    #[test]
+    fn renders_in_lists() {
+        assert_eq!(col("id").r#in([1, 2, 3]).to_string(), "id.in.(1,2,3)");
+        assert_eq!(
+            col("name").r#in(["a", "b,c"]).to_string(),
+            "name.in.(a,\"b,c\")"
+        );
+        assert_eq!(Operand::List(vec!["b c".into()]).render(), "(\"b c\")");
+    }
+
+    #[test]
+    fn lowers_null_equality_to_is() {
+        assert_eq!(col("a").eq(None::<i32>).to_string(), "a.is.null");
+        assert_eq!(col("a").neq(None::<i32>).to_string(), "a.not.is.null");
+    }
+
+    #[test]
+    fn pushes_negation_down() {
+        let expr = Expr::and([col("a").eq(1), col("b").gt(2)]).not();
+        assert_eq!(expr.to_string(), "or(a.not.eq.1,b.not.gt.2)");
+        let expr = Expr::or([col("a").eq(1).not(), col("b").is(None)]).not();
+        assert_eq!(expr.to_string(), "and(a.eq.1,b.not.is.null)");
+    }
+
+    #[test]
+    fn renders_nested_groups() {
+        let expr = Expr::or([
+            col("name").eq("New Zealand"),
+            Expr::and([col("id").gt(10), col("email").ilike("%.com")]),
+        ]);
+        assert_eq!(
+            expr.to_string(),
+            "or(name.eq.\"New Zealand\",and(id.gt.10,email.ilike.\"%.com\"))"
+        );
+    }
+
+    #[test]
+    fn folds_empty_groups() {
+        let a = || col("a").eq(1);
+        assert_eq!(Expr::and([]).fold(), Folded::Always(true));
+        assert_eq!(Expr::or([]).fold(), Folded::Always(false));
+        // an empty `and` matches every row, so the whole `or` does
+        assert_eq!(Expr::or([a(), Expr::and([])]).fold(), Folded::Always(true));
+        // an empty `or` matches no row, so the whole `and` doesn't
+        assert_eq!(Expr::and([a(), Expr::or([])]).fold(), Folded::Always(false));
+        assert_eq!(Expr::and([a(), Expr::and([])]).fold(), Folded::Expr(a()));
+        assert_eq!(Expr::or([a(), Expr::or([])]).fold(), Folded::Expr(a()));
+        assert_eq!(
+            Expr::or([a(), Expr::or([Expr::and([])])]).fold(),
+            Folded::Always(true)
+        );
+        assert_eq!(
+            Expr::and([a(), Expr::or([col("b").eq(2), Expr::or([])])]).fold(),
+            Folded::Expr(Expr::and([a(), col("b").eq(2)]))
+        );
+    }
+}
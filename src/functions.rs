@@ -0,0 +1,127 @@
+//! Options for invoking Edge Functions through [`Functions`](crate::Functions).
+
+use js_sys::{Object, Uint8Array};
+use wasm_bindgen::JsValue;
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::error::set;
+#[cfg(feature = "serde")]
+use crate::error::to_value;
+#[cfg(feature = "serde")]
+use crate::SupabaseError;
+
+/// Request body of a function call
+#[derive(Debug, Clone)]
+pub enum FunctionBody {
+    /// Sent as `application/json`.
+    Json(JsValue),
+    /// Sent as `text/plain`.
+    Text(String),
+    /// Sent as `application/octet-stream`.
+    Binary(Vec<u8>),
+}
+
+impl FunctionBody {
+    /// Serialize `value` into a JSON body.
+    #[cfg(feature = "serde")]
+    pub fn json<T: Serialize + ?Sized>(value: &T) -> Result<Self, SupabaseError> {
+        Ok(FunctionBody::Json(to_value(value)?))
+    }
+
+    fn to_js(&self) -> JsValue {
+        match self {
+            FunctionBody::Json(value) => value.clone(),
+            FunctionBody::Text(text) => JsValue::from_str(text),
+            // functions-js only treats `Blob` and `ArrayBuffer` as binary
+            FunctionBody::Binary(bytes) => Uint8Array::from(bytes.as_slice()).buffer().into(),
+        }
+    }
+}
+
+/// HTTP method of a function call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FunctionMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl FunctionMethod {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FunctionMethod::Get => "GET",
+            FunctionMethod::Post => "POST",
+            FunctionMethod::Put => "PUT",
+            FunctionMethod::Patch => "PATCH",
+            FunctionMethod::Delete => "DELETE",
+        }
+    }
+}
+
+/// Options to invoke a function
+///
+/// Converts into the options object of `functions.invoke(name, options)`.
+#[derive(Debug, Clone, Default)]
+pub struct FunctionInvokeOptions {
+    headers: Vec<(String, String)>,
+    method: Option<FunctionMethod>,
+    body: Option<FunctionBody>,
+    region: Option<String>,
+}
+
+impl FunctionInvokeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a request header.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Set the HTTP method, `POST` by default.
+    pub fn method(mut self, method: FunctionMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Set the request body.
+    pub fn body(mut self, body: FunctionBody) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Set the region to invoke the function in, e.g. `us-east-1`.
+    pub fn region(mut self, region: &str) -> Self {
+        self.region = Some(region.to_string());
+        self
+    }
+}
+
+impl From<FunctionInvokeOptions> for JsValue {
+    fn from(options: FunctionInvokeOptions) -> Self {
+        let object = Object::new();
+        if !options.headers.is_empty() {
+            let headers = Object::new();
+            for (name, value) in &options.headers {
+                set(&headers, name, &JsValue::from_str(value));
+            }
+            set(&object, "headers", &headers);
+        }
+        if let Some(method) = options.method {
+            set(&object, "method", &JsValue::from_str(method.as_str()));
+        }
+        if let Some(body) = &options.body {
+            set(&object, "body", &body.to_js());
+        }
+        if let Some(region) = &options.region {
+            set(&object, "region", &JsValue::from_str(region));
+        }
+        object.into()
+    }
+}
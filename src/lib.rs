@@ -3,6 +3,56 @@
 
 use wasm_bindgen::prelude::*;
 
+mod auth;
+mod auth_state;
+mod client;
+mod columns;
+mod database;
+mod error;
+mod filter;
+mod functions;
+mod otp;
+mod query;
+mod realtime;
+mod storage;
+#[cfg(feature = "serde")]
+mod stream;
+
+#[cfg(feature = "serde")]
+pub use auth::{
+    AuthResponse, AuthenticationMethod, AuthenticatorAssurance, AuthenticatorAssuranceLevel,
+    Factor, Factors, MfaChallenge, MfaEnrollment, Session, TotpSecret, User, UserIdentity,
+};
+pub use auth_state::AuthChangeEvent;
+#[cfg(feature = "serde")]
+pub use auth_state::Subscription;
+pub use client::{create_client_with_options, ClientOptions, FlowType};
+pub use columns::{Columns, Embed};
+#[cfg(feature = "serde")]
+pub use database::Returns;
+pub use database::{
+    Count, DeleteOptions, ExplainFormat, ExplainOptions, InsertOptions, LimitOptions, OrderOptions,
+    QueryResponse, RangeOptions, RpcOptions, SelectOptions, UpdateOptions, UpsertOptions,
+};
+pub use error::{IntoSupabaseResult, SupabaseError};
+pub use filter::{col, Column, Expr, Operand};
+pub use functions::{FunctionBody, FunctionInvokeOptions, FunctionMethod};
+pub use otp::{EmailOtpType, MobileOtpType, ResendParams, VerifyOtpParams};
+pub use query::{FilterValue, QueryBuilder};
+pub use realtime::{
+    ChannelConfig, PostgresChangesEvent, PostgresChangesFilter, PresenceEvent, SendStatus,
+    SubscribeStatus,
+};
+#[cfg(feature = "serde")]
+pub use realtime::{PostgresChangesPayload, PresenceChange};
+#[cfg(feature = "serde")]
+pub use storage::Bucket;
+pub use storage::{
+    BucketOptions, FileBody, FileOptions, SearchOptions, TransformOptions, UrlOptions,
+};
+#[cfg(feature = "serde")]
+pub use stream::{AuthStateStream, RealtimeStream, RealtimeStreams, RealtimeSubscription};
+
 /// Sign in with email and password credentials
 #[wasm_bindgen(getter_with_clone)]
 pub struct Credentials {
@@ -22,21 +72,71 @@ pub struct CurrentSession {
     pub refresh_token: String,
 }
 
-/*
+/// Enroll a factor
+#[wasm_bindgen(getter_with_clone)]
+pub struct MFAEnrollParams {
+    /// `totp` or `phone`.
+    #[wasm_bindgen(js_name = factorType)]
+    pub factor_type: String,
+    #[wasm_bindgen(js_name = friendlyName)]
+    pub friendly_name: Option<String>,
+    /// Domain shown in the authenticator app, TOTP factors only.
+    pub issuer: Option<String>,
+    /// Phone number to send codes to, phone factors only.
+    pub phone: Option<String>,
+}
+
+impl MFAEnrollParams {
+    /// Enroll a TOTP factor
+    pub fn totp(friendly_name: Option<String>, issuer: Option<String>) -> Self {
+        Self {
+            factor_type: "totp".to_string(),
+            friendly_name,
+            issuer,
+            phone: None,
+        }
+    }
+
+    /// Enroll a phone factor
+    pub fn phone(phone: String, friendly_name: Option<String>) -> Self {
+        Self {
+            factor_type: "phone".to_string(),
+            friendly_name,
+            issuer: None,
+            phone: Some(phone),
+        }
+    }
+}
+
 #[wasm_bindgen(getter_with_clone)]
 pub struct MFAChallengeParams {
+    #[wasm_bindgen(js_name = factorId)]
     pub factor_id: String,
+    /// `sms` or `whatsapp`, phone factors only.
+    pub channel: Option<String>,
 }
-*/
 
-/*
 #[wasm_bindgen(getter_with_clone)]
 pub struct MFAVerifyParams {
+    #[wasm_bindgen(js_name = factorId)]
     pub factor_id: String,
+    #[wasm_bindgen(js_name = challengeId)]
     pub challenge_id: String,
     pub code: String,
 }
-*/
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct MFAChallengeAndVerifyParams {
+    #[wasm_bindgen(js_name = factorId)]
+    pub factor_id: String,
+    pub code: String,
+}
+
+#[wasm_bindgen(getter_with_clone)]
+pub struct MFAUnenrollParams {
+    #[wasm_bindgen(js_name = factorId)]
+    pub factor_id: String,
+}
 
 #[wasm_bindgen]
 extern "C" {
@@ -49,335 +149,147 @@ extern "C" {
     #[wasm_bindgen(js_namespace = ["supabase"], js_name = createClient)]
     pub fn create_client(supabase_url: &str, supabase_key: &str) -> SupabaseClient;
 
+    #[wasm_bindgen(js_namespace = ["supabase"], js_name = createClient)]
+    fn create_client_js(supabase_url: &str, supabase_key: &str, options: JsValue)
+        -> SupabaseClient;
+
     #[wasm_bindgen(method, js_name = from)]
     pub fn from(this: &SupabaseClient, table: &str) -> Database;
 
+    #[wasm_bindgen(method, js_name = rpc)]
+    fn rpc_js(this: &SupabaseClient, function: &str, args: JsValue, options: JsValue) -> Database;
+
+    #[derive(Debug, Clone)]
     pub type Database;
 
-    #[wasm_bindgen(method, catch, js_name = select)]
-    pub async fn select(this: &Database, columns: Option<&str>) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = select)]
     pub fn select_(this: &Database, columns: Option<&str>) -> Database;
+    #[wasm_bindgen(method, js_name = select)]
+    fn select_with_options_js(this: &Database, columns: Option<&str>, options: JsValue)
+        -> Database;
 
-    /// # Order the query
-    ///
-    /// Order query result by column.
-    ///
-    /// ```ignore
-    /// #[derive(Serialize, Deserialize)]
-    /// #[serde(rename_all = "camelCase")]
-    /// struct OrderOptions {
-    ///     foreign_table: String,
-    ///     nulls_first: bool,
-    ///     ascending: bool,
-    /// }
-    /// let data: JsValue = client
-    /// .get()
-    /// .from("countries")
-    /// .select_(Some("name, cities ( name )"))
-    /// .order(
-    ///    "name",
-    ///    serde_wasm_bindgen::to_value(&OrderOptions {
-    ///     foreign_table: "cities".to_string(),
-    ///     nulls_first: false,
-    ///     ascending: true,
-    ///    }).unwrap(),
-    /// )
-    /// .await.unwrap();
-    /// ```
-    ///
-    #[wasm_bindgen(method, catch, js_name = order)]
-    pub async fn order(this: &Database, column: &str, options: JsValue)
-        -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = order)]
     pub fn order_(this: &Database, column: &str, options: JsValue) -> Database;
 
-    /// # Limit the query
-    ///
-    /// Limit the query result by count.
-    ///
-    #[wasm_bindgen(method, catch, js_name = limit)]
-    pub async fn limit(this: &Database, count: u32) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = limit)]
     pub fn limit_(this: &Database, count: u32) -> Database;
+    #[wasm_bindgen(method, js_name = limit)]
+    fn limit_with_options_js(this: &Database, count: u32, options: JsValue) -> Database;
 
-    /// # Limit the query to a range
-    ///
-    /// Limit the query result by from and to inclusively.
-    ///
-    #[wasm_bindgen(method, catch, js_name = range)]
-    pub async fn range(this: &Database, from: u32, to: u32) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = range)]
     pub fn range_(this: &Database, from: u32, to: u32) -> Database;
+    #[wasm_bindgen(method, js_name = range)]
+    fn range_with_options_js(this: &Database, from: u32, to: u32, options: JsValue) -> Database;
 
-    /// # Retrieve the query as one row
-    ///
-    /// Return data as a single object instead of an array of objects.
-    ///
-    #[wasm_bindgen(method, catch, js_name = single)]
-    pub async fn single(this: &Database) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(method, js_name = single)]
+    pub fn single_(this: &Database) -> Database;
 
-    /// # Retrieve the query as 0-1 rows
-    ///
-    /// Return data as a single object instead of an array of objects.
-    ///
-    #[wasm_bindgen(method, catch, js_name = maybeSingle)]
-    pub async fn maybe_single(this: &Database) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(method, js_name = maybeSingle)]
+    pub fn maybe_single_(this: &Database) -> Database;
 
-    /// # Retrieve the query as a CSV string
-    ///
-    /// Return data as a string in CSV format.
-    ///
-    /// ```ignore
-    /// let csv = client.get().from("countries").select_(Some("*")).csv().await.unwrap();
-    /// ```
-    ///
-    #[wasm_bindgen(method, catch, js_name = csv)]
-    pub async fn csv(this: &Database) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(method, js_name = csv)]
+    pub fn csv_(this: &Database) -> Database;
+
+    #[wasm_bindgen(method, js_name = geojson)]
+    pub fn geojson_(this: &Database) -> Database;
+
+    #[wasm_bindgen(method, js_name = abortSignal)]
+    pub fn abort_signal_(this: &Database, signal: &web_sys::AbortSignal) -> Database;
+
+    #[wasm_bindgen(method, js_name = throwOnError)]
+    pub fn throw_on_error_(this: &Database) -> Database;
+
+    #[wasm_bindgen(method, js_name = explain)]
+    fn explain_js(this: &Database, options: JsValue) -> Database;
 
-    /// # Column is equal to a value
-    ///
-    /// Match only rows where column is equal to value.
-    ///
-    #[wasm_bindgen(method, catch, js_name = eq)]
-    pub async fn eq(this: &Database, column: &str, value: &JsValue) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = eq)]
     pub fn eq_(this: &Database, column: &str, value: &JsValue) -> Database;
 
-    /// # Column is not equal to a value
-    ///
-    /// Match only rows where column is not equal to value.
-    ///
-    #[wasm_bindgen(method, catch, js_name = neq)]
-    pub async fn neq(this: &Database, column: &str, value: &JsValue) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = neq)]
     pub fn neq_(this: &Database, column: &str, value: &JsValue) -> Database;
 
-    /// # Column is greater than a value
-    ///
-    /// Match only rows where column is greater than value.
-    ///
-    #[wasm_bindgen(method, catch, js_name = gt)]
-    pub async fn gt(this: &Database, column: &str, value: &JsValue) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = gt)]
     pub fn gt_(this: &Database, column: &str, value: &JsValue) -> Database;
 
-    /// # Column is greater than or equal to a value
-    ///
-    /// Match only rows where column is greater than or equal to value.
-    ///
-    #[wasm_bindgen(method, catch, js_name = gte)]
-    pub async fn gte(this: &Database, column: &str, value: &JsValue) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = gte)]
     pub fn gte_(this: &Database, column: &str, value: &JsValue) -> Database;
 
-    /// # Column is less than a value
-    ///
-    /// Match only rows where column is less than value.
-    ///
-    #[wasm_bindgen(method, catch, js_name = lt)]
-    pub async fn lt(this: &Database, column: &str, value: &JsValue) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = lt)]
     pub fn lt_(this: &Database, column: &str, value: &JsValue) -> Database;
 
-    /// # Column is less than or equal to a value
-    ///
-    /// Match only rows where column is less than or equal to value.
-    ///
-    #[wasm_bindgen(method, catch, js_name = lte)]
-    pub async fn lte(this: &Database, column: &str, value: &JsValue) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = lte)]
     pub fn lte_(this: &Database, column: &str, value: &JsValue) -> Database;
 
-    /// # Column matches a pattern
-    ///
-    /// Match only rows where column matches pattern case-sensitively.
-    ///
-    #[wasm_bindgen(method, catch, js_name = like)]
-    pub async fn like(this: &Database, column: &str, pattern: &str) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = like)]
     pub fn like_(this: &Database, column: &str, pattern: &str) -> Database;
 
-    /// # Column matches a case-insensitive pattern
-    ///
-    /// Match only rows where column matches pattern case-insensitively.
-    ///
-    /// ```ignore
-    /// client.from("countries").select(None).ilike(&"name", &"%alba%").await;
-    /// ```
-    ///
-    #[wasm_bindgen(method, catch, js_name = ilike)]
-    pub async fn ilike(this: &Database, column: &str, pattern: &str) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = ilike)]
     pub fn ilike_(this: &Database, column: &str, pattern: &str) -> Database;
 
-    /// # Column is a value
-    ///
-    /// Match only rows where column IS value.
-    ///
-    /// ```ignore
-    /// // check for nullness
-    /// client.from("countries").select(None).is("name", JsValue::NULL);
-    /// // or check for true of false
-    /// client.from("countries").select(None).is("name", JsValue::TRUE);
-    /// ```
-    ///
-    #[wasm_bindgen(method, catch, js_name = is)]
-    pub async fn is(this: &Database, column: &str, value: &JsValue) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = is)]
     pub fn is_(this: &Database, column: &str, value: &JsValue) -> Database;
 
-    /// # Column is in an array
-    ///
-    /// Match only rows where column is included in the values array.
-    ///
-    #[wasm_bindgen(method, catch, js_name = in)]
-    pub async fn r#in(
-        this: &Database,
-        column: &str,
-        values: Vec<JsValue>,
-    ) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = in)]
     pub fn r#in_(this: &Database, column: &str, values: Vec<JsValue>) -> Database;
 
-    /// # Column contains every element in a value
-    ///
-    /// Only relevant for jsonb, array, and range columns. Match only rows where column contains every element appearing in value.
-    ///
-    #[wasm_bindgen(method, catch, js_name = contains)]
-    pub async fn contains(
-        this: &Database,
-        column: &str,
-        value: JsValue,
-    ) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = contains)]
     pub fn contains_(this: &Database, column: &str, value: JsValue) -> Database;
 
-    /// # Contained by value
-    ///
-    /// Only relevant for jsonb, array, and range columns. Match only rows where every element appearing in column is contained by value.
-    ///
-    #[wasm_bindgen(method, catch, js_name = containedBy)]
-    pub async fn contained_by(
-        this: &Database,
-        column: &str,
-        value: JsValue,
-    ) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = containedBy)]
     pub fn contained_by_(this: &Database, column: &str, value: JsValue) -> Database;
 
-    /// # Greater than a range
-    ///
-    /// Only relevant for range columns. Match only rows where every element in column is greater than any element in range.
-    ///
-    #[wasm_bindgen(method, catch, js_name = rangeGt)]
-    pub async fn range_gt(this: &Database, column: &str, range: &str) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = rangeGt)]
     pub fn range_gt_(this: &Database, column: &str, range: &str) -> Database;
 
-    /// # Greater than or equal to a range
-    ///
-    /// Only relevant for range columns. Match only rows where every element in column is either contained in range or greater than any element in range.
-    ///
-    #[wasm_bindgen(method, catch, js_name = rangeGte)]
-    pub async fn range_gte(this: &Database, column: &str, range: &str) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = rangeGte)]
     pub fn range_gte_(this: &Database, column: &str, range: &str) -> Database;
 
-    /// # Less than a range
-    ///
-    /// Only relevant for range columns. Match only rows where every element in column is less than any element in range.
-    ///
-    #[wasm_bindgen(method, catch, js_name = rangeLt)]
-    pub async fn range_lt(this: &Database, column: &str, range: &str) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = rangeLt)]
     pub fn range_lt_(this: &Database, column: &str, range: &str) -> Database;
 
-    /// # Less than or equal to a range
-    ///
-    /// Only relevant for range columns. Match only rows where every element in column is either contained in range or less than any element in range.
-    ///
-    #[wasm_bindgen(method, catch, js_name = rangeLte)]
-    pub async fn range_lte(this: &Database, column: &str, range: &str) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = rangeLte)]
     pub fn range_lte_(this: &Database, column: &str, range: &str) -> Database;
 
-    /// # Mutually exclusive to a range
-    ///
-    /// Only relevant for range columns. Match only rows where column is mutually exclusive to range and there can be no element between the two ranges.
-    ///
-    #[wasm_bindgen(method, catch, js_name = rangeAdjacent)]
-    pub async fn range_adjacent(
-        this: &Database,
-        column: &str,
-        range: &str,
-    ) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = rangeAdjacent)]
     pub fn range_adjacent_(this: &Database, column: &str, range: &str) -> Database;
 
-    /// # With a common element
-    ///
-    /// Only relevant for array and range columns. Match only rows where column and value have an element in common.
-    ///
-    #[wasm_bindgen(method, catch, js_name = overlaps)]
-    pub async fn overlaps(
-        this: &Database,
-        column: &str,
-        value: JsValue,
-    ) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = overlaps)]
     pub fn overlaps_(this: &Database, column: &str, value: JsValue) -> Database;
 
-    /// # Match a string
-    ///
-    /// Only relevant for text and tsvector columns. Match only rows where column matches the query string in query.
-    ///
-    #[wasm_bindgen(method, catch, js_name = textSearch)]
-    pub async fn text_search(
-        this: &Database,
-        column: &str,
-        query: &str,
-        options: JsValue,
-    ) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = textSearch)]
     pub fn text_search_(this: &Database, column: &str, query: &str, options: JsValue) -> Database;
 
-    /// # Update data
-    ///
-    /// Perform an UPDATE on the table or view.
-    ///
-    #[wasm_bindgen(method, catch, js_name = update)]
-    pub async fn update(this: &Database, values: &JsValue) -> Result<JsValue, JsValue>;
+    #[wasm_bindgen(method, js_name = not)]
+    pub fn not_(this: &Database, column: &str, operator: &str, value: &JsValue) -> Database;
+
+    #[wasm_bindgen(method, js_name = or)]
+    pub fn or_(this: &Database, filters: &str, options: JsValue) -> Database;
+
+    #[wasm_bindgen(method, js_name = match)]
+    pub fn r#match_(this: &Database, query: JsValue) -> Database;
+
+    #[wasm_bindgen(method, js_name = filter)]
+    pub fn filter_(this: &Database, column: &str, operator: &str, value: &JsValue) -> Database;
+
     #[wasm_bindgen(method, js_name = update)]
     pub fn update_(this: &Database, values: &JsValue) -> Database;
+    #[wasm_bindgen(method, js_name = update)]
+    fn update_with_options_js(this: &Database, values: &JsValue, options: JsValue) -> Database;
 
-    /// # Upsert data
-    ///
-    /// Perform an UPSERT on the table or view.
-    ///
     #[wasm_bindgen(method, js_name = upsert)]
-    pub fn upsert(this: &Database, values: JsValue) -> Database;
+    pub fn upsert_(this: &Database, values: JsValue) -> Database;
+    #[wasm_bindgen(method, js_name = upsert)]
+    fn upsert_with_options_js(this: &Database, values: JsValue, options: JsValue) -> Database;
 
-    /// # Delete data
-    ///
-    /// Should always be combined with filters
-    ///
-    /// ```ignore
-    /// let client = supabase_js_rs::create_client("https://xyzcompany.supabase.co", "public-anon-key");
-    /// let res: Result<JsValue, JsValue> = client.from("countries").delete().eq("id", 1.into_js_result().unwrap()).await;
-    /// ```
-    ///
     #[wasm_bindgen(method, js_name = delete)]
-    pub fn delete(this: &Database) -> Database;
+    pub fn delete_(this: &Database) -> Database;
+    #[wasm_bindgen(method, js_name = delete)]
+    fn delete_with_options_js(this: &Database, options: JsValue) -> Database;
 
-    /// # Insert data
-    ///
-    /// Perform an INSERT into the table or view.
-    ///
-    #[wasm_bindgen(method, catch, js_name = insert)]
-    pub async fn insert(this: &Database, values: JsValue) -> Result<JsValue, JsValue>;
     #[wasm_bindgen(method, js_name = insert)]
     pub fn insert_(this: &Database, values: JsValue) -> Database;
+    #[wasm_bindgen(method, js_name = insert)]
+    fn insert_with_options_js(this: &Database, values: JsValue, options: JsValue) -> Database;
 
     /// Auth methods
     #[wasm_bindgen(method, getter = auth)]
@@ -410,6 +322,12 @@ extern "C" {
     #[wasm_bindgen(method, catch, js_name = signInWithOtp)]
     pub async fn sign_in_with_otp(this: &Auth, credentials: JsValue) -> Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(method, catch, js_name = verifyOtp)]
+    async fn verify_otp_js(this: &Auth, params: JsValue) -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(method, catch, js_name = resend)]
+    async fn resend_js(this: &Auth, credentials: JsValue) -> Result<JsValue, JsValue>;
+
     /// # Sign in a user through OAuth
     ///
     /// Log in an existing user via a third-party provider.
@@ -471,6 +389,17 @@ extern "C" {
     #[wasm_bindgen(method, js_name = onAuthStateChange)]
     pub fn on_auth_state_change(this: &Auth, callback: &Closure<dyn FnMut(JsValue, JsValue)>);
 
+    #[wasm_bindgen(method, js_name = onAuthStateChange)]
+    fn on_auth_state_change_js(
+        this: &Auth,
+        callback: &Closure<dyn FnMut(JsValue, JsValue)>,
+    ) -> JsValue;
+
+    type AuthSubscription;
+
+    #[wasm_bindgen(method)]
+    fn unsubscribe(this: &AuthSubscription);
+
     /// # Send a password reset request
     ///
     /// Sends a password reset request to an email address.
@@ -482,34 +411,92 @@ extern "C" {
         options: JsValue,
     ) -> Result<JsValue, JsValue>;
 
-    /*
-    pub type Mfa;
-
+    /// Multi-factor authentication methods
     #[wasm_bindgen(method, getter = mfa)]
     pub fn mfa(this: &Auth) -> Mfa;
 
-    /// Create a challenge
+    pub type Mfa;
+
+    /// # Enroll a factor
+    ///
+    /// Starts the enrollment of a TOTP or phone factor. TOTP enrollment returns a QR code and secret.
+    ///
+    /// ```ignore
+    /// let params = MFAEnrollParams::totp(Some("Work laptop".into()), None);
+    /// let res = client.auth().mfa().enroll(params).await;
+    /// ```
+    ///
+    #[wasm_bindgen(method, catch, js_name = enroll)]
+    pub async fn enroll(this: &Mfa, params: MFAEnrollParams) -> Result<JsValue, JsValue>;
+
+    /// # Create a challenge
+    ///
+    /// Prepares a challenge used to verify that a user has access to a factor.
+    ///
     #[wasm_bindgen(method, catch, js_name = challenge)]
-    pub fn challenge(this: &Mfa, params: MFAChallengeParams) -> Result<JsValue, JsValue>;
+    pub async fn challenge(this: &Mfa, params: MFAChallengeParams) -> Result<JsValue, JsValue>;
 
-    /// Verify a challenge
+    /// # Verify a challenge
+    ///
+    /// Verifies a code against a challenge and upgrades the session to `aal2`.
+    ///
     #[wasm_bindgen(method, catch, js_name = verify)]
-    pub fn verify(this: &Mfa, params: MFAVerifyParams) -> Result<JsValue, JsValue>;
-    */
+    pub async fn verify(this: &Mfa, params: MFAVerifyParams) -> Result<JsValue, JsValue>;
+
+    /// # Create and verify a challenge
+    ///
+    /// Creates a challenge and immediately verifies the code against it.
+    ///
+    #[wasm_bindgen(method, catch, js_name = challengeAndVerify)]
+    pub async fn challenge_and_verify(
+        this: &Mfa,
+        params: MFAChallengeAndVerifyParams,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Unenroll a factor
+    ///
+    #[wasm_bindgen(method, catch, js_name = unenroll)]
+    pub async fn unenroll(this: &Mfa, params: MFAUnenrollParams) -> Result<JsValue, JsValue>;
+
+    /// # List all factors
+    ///
+    /// Returns the factors of the current user, grouped by type.
+    ///
+    #[wasm_bindgen(method, catch, js_name = listFactors)]
+    pub async fn list_factors(this: &Mfa) -> Result<JsValue, JsValue>;
+
+    /// # Get Authenticator Assurance Level
+    ///
+    /// Returns the current and the next possible assurance level of the session.
+    ///
+    #[wasm_bindgen(method, catch, js_name = getAuthenticatorAssuranceLevel)]
+    pub async fn get_authenticator_assurance_level(this: &Mfa) -> Result<JsValue, JsValue>;
 
     #[wasm_bindgen(method, js_name = channel)]
     pub fn channel(this: &SupabaseClient, name: &str) -> RealtimeChannel;
 
+    #[wasm_bindgen(method, js_name = channel)]
+    fn channel_js(this: &SupabaseClient, name: &str, opts: JsValue) -> RealtimeChannel;
+
     /// # Unsubscribe from all channels
     ///
     #[wasm_bindgen(method, js_name = removeAllChannels)]
     pub fn remove_all_channels(this: &SupabaseClient);
 
-    /// # Retrieve all channels
+    /// # Unsubscribe and remove a channel
     ///
+    /// Resolves with `ok`, `timed out` or `error`.
+    ///
+    #[wasm_bindgen(method, catch, js_name = removeChannel)]
+    pub async fn remove_channel(
+        this: &SupabaseClient,
+        channel: &RealtimeChannel,
+    ) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(method, js_name = getChannels)]
-    pub fn get_channels(this: &SupabaseClient) -> JsValue;
+    fn get_channels_js(this: &SupabaseClient) -> js_sys::Array;
 
+    #[derive(Debug, Clone)]
     pub type RealtimeChannel;
 
     /// # Subscribe to database changes
@@ -528,17 +515,101 @@ extern "C" {
         callback: Option<&Closure<dyn FnMut(JsValue, JsValue)>>,
     ) -> RealtimeChannel;
 
-    #[wasm_bindgen(method, js_name = storage)]
-    pub fn storage(this: &SupabaseClient) -> Storage;
+    /// # Unsubscribe from a channel
+    ///
+    /// Leaves the channel and resolves with `ok`, `timed out` or `error`.
+    ///
+    #[wasm_bindgen(method, catch, js_name = unsubscribe)]
+    pub async fn unsubscribe(this: &RealtimeChannel) -> Result<JsValue, JsValue>;
 
-    pub type Storage;
+    type RealtimeClient;
+
+    #[wasm_bindgen(method, getter = socket)]
+    fn socket(this: &RealtimeChannel) -> RealtimeClient;
 
-    /// # Create a bucket
+    #[wasm_bindgen(method, js_name = removeChannel)]
+    fn remove_channel_js(this: &RealtimeClient, channel: &RealtimeChannel) -> js_sys::Promise;
+
+    /// # Send a message
     ///
-    /// Creates a new Storage bucket
+    /// Sends `{ type, event, payload }` to the channel and resolves with `ok`, `timed out`,
+    /// `rate limited` or `error`. See [`RealtimeChannel::send_broadcast`].
     ///
+    #[wasm_bindgen(method, catch, js_name = send)]
+    pub async fn send(
+        this: &RealtimeChannel,
+        args: JsValue,
+        opts: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Track presence
+    ///
+    /// Shares `payload` with other subscribers of the channel and resolves with the send status.
+    ///
+    #[wasm_bindgen(method, catch, js_name = track)]
+    pub async fn track(
+        this: &RealtimeChannel,
+        payload: &JsValue,
+        opts: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Stop tracking presence
+    ///
+    #[wasm_bindgen(method, catch, js_name = untrack)]
+    pub async fn untrack(this: &RealtimeChannel, opts: JsValue) -> Result<JsValue, JsValue>;
+
+    /// # Retrieve presence state
+    ///
+    /// Returns an object mapping presence keys to tracked payloads.
+    ///
+    #[wasm_bindgen(method, js_name = presenceState)]
+    pub fn presence_state(this: &RealtimeChannel) -> JsValue;
+
+    /// Edge Functions methods
+    #[wasm_bindgen(method, getter = functions)]
+    pub fn functions(this: &SupabaseClient) -> Functions;
+
+    pub type Functions;
+
+    /// # Invoke a Supabase Edge Function
+    ///
+    /// Invokes a function by name. Build `options` with [`FunctionInvokeOptions`].
+    ///
+    /// ```ignore
+    /// use supabase_js_rs::{FunctionBody, FunctionInvokeOptions, IntoSupabaseResult};
+    ///
+    /// let options = FunctionInvokeOptions::new()
+    ///     .body(FunctionBody::Text("Functions".into()))
+    ///     .header("x-my-header", "value");
+    /// let data = client.functions().invoke("hello", options.into()).await.into_data()?;
+    /// ```
+    ///
+    #[wasm_bindgen(method, catch, js_name = invoke)]
+    pub async fn invoke(
+        this: &Functions,
+        function_name: &str,
+        options: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Update the authorization header
+    ///
+    /// Sets the access token used to invoke functions.
+    ///
+    #[wasm_bindgen(method, js_name = setAuth)]
+    pub fn set_auth(this: &Functions, token: &str);
+
+    /// Storage methods
+    #[wasm_bindgen(method, getter = storage)]
+    pub fn storage(this: &SupabaseClient) -> Storage;
+
+    pub type Storage;
+
     #[wasm_bindgen(method, catch, js_name = createBucket)]
-    pub async fn create_bucket(this: &Storage, id: &str) -> Result<JsValue, JsValue>;
+    async fn create_bucket_js(
+        this: &Storage,
+        id: &str,
+        options: JsValue,
+    ) -> Result<JsValue, JsValue>;
 
     /// # Retrieve a bucket
     ///
@@ -554,12 +625,12 @@ extern "C" {
     #[wasm_bindgen(method, catch, js_name = listBuckets)]
     pub async fn list_buckets(this: &Storage) -> Result<JsValue, JsValue>;
 
-    /// # Update a bucket
-    ///
-    /// Updates a Storage bucket
-    ///
     #[wasm_bindgen(method, catch, js_name = updateBucket)]
-    pub async fn update_bucket(this: &Storage, options: JsValue) -> Result<JsValue, JsValue>;
+    async fn update_bucket_js(
+        this: &Storage,
+        id: &str,
+        options: JsValue,
+    ) -> Result<JsValue, JsValue>;
 
     /// # Empty a bucket
     ///
@@ -575,4 +646,153 @@ extern "C" {
     #[wasm_bindgen(method, catch, js_name = deleteBucket)]
     pub async fn delete_bucket(this: &Storage, id: &str) -> Result<JsValue, JsValue>;
 
+    /// # Access a bucket
+    ///
+    /// Perform file operations in a bucket.
+    ///
+    #[wasm_bindgen(method, js_name = from)]
+    pub fn from(this: &Storage, id: &str) -> StorageFileApi;
+
+    pub type StorageFileApi;
+
+    /// # Upload a file
+    ///
+    /// Uploads a file to an existing bucket.
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .storage()
+    ///     .from("avatars")
+    ///     .upload(
+    ///         "public/avatar1.png",
+    ///         FileBody::from(file).into(),
+    ///         FileOptions::new().cache_control(3600).upsert(false).into(),
+    ///     )
+    ///     .await;
+    /// ```
+    ///
+    #[wasm_bindgen(method, catch, js_name = upload)]
+    pub async fn upload(
+        this: &StorageFileApi,
+        path: &str,
+        file_body: JsValue,
+        file_options: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Replace an existing file
+    ///
+    /// Replaces an existing file at the specified path with a new one.
+    ///
+    #[wasm_bindgen(method, catch, js_name = update)]
+    pub async fn update(
+        this: &StorageFileApi,
+        path: &str,
+        file_body: JsValue,
+        file_options: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Download a file
+    ///
+    /// Downloads a file from a private bucket as a `Blob`. Pass [`UrlOptions`] to transform images.
+    ///
+    #[wasm_bindgen(method, catch, js_name = download)]
+    pub async fn download(
+        this: &StorageFileApi,
+        path: &str,
+        options: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # List all files in a bucket
+    ///
+    /// Lists all the files within a bucket. Pass [`SearchOptions`] to paginate and sort.
+    ///
+    #[wasm_bindgen(method, catch, js_name = list)]
+    pub async fn list(
+        this: &StorageFileApi,
+        path: Option<&str>,
+        options: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Move an existing file
+    ///
+    /// Moves an existing file to a new path in the same bucket.
+    ///
+    #[wasm_bindgen(method, catch, js_name = move)]
+    pub async fn r#move(
+        this: &StorageFileApi,
+        from_path: &str,
+        to_path: &str,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Copy an existing file
+    ///
+    /// Copies an existing file to a new path in the same bucket.
+    ///
+    #[wasm_bindgen(method, catch, js_name = copy)]
+    pub async fn copy(
+        this: &StorageFileApi,
+        from_path: &str,
+        to_path: &str,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Delete files in a bucket
+    ///
+    /// Deletes files within the same bucket.
+    ///
+    #[wasm_bindgen(method, catch, js_name = remove)]
+    pub async fn remove(this: &StorageFileApi, paths: Vec<JsValue>) -> Result<JsValue, JsValue>;
+
+    /// # Retrieve public URL
+    ///
+    /// Returns `{ data: { publicUrl } }` for a file in a public bucket.
+    ///
+    #[wasm_bindgen(method, js_name = getPublicUrl)]
+    pub fn get_public_url(this: &StorageFileApi, path: &str, options: JsValue) -> JsValue;
+
+    /// # Create a signed URL
+    ///
+    /// Creates a signed URL to share a file for a fixed amount of time.
+    ///
+    #[wasm_bindgen(method, catch, js_name = createSignedUrl)]
+    pub async fn create_signed_url(
+        this: &StorageFileApi,
+        path: &str,
+        expires_in: u32,
+        options: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Create signed URLs
+    ///
+    /// Creates multiple signed URLs to share files for a fixed amount of time.
+    ///
+    #[wasm_bindgen(method, catch, js_name = createSignedUrls)]
+    pub async fn create_signed_urls(
+        this: &StorageFileApi,
+        paths: Vec<JsValue>,
+        expires_in: u32,
+        options: JsValue,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Create signed upload URL
+    ///
+    /// Creates a signed upload URL valid for two hours.
+    ///
+    #[wasm_bindgen(method, catch, js_name = createSignedUploadUrl)]
+    pub async fn create_signed_upload_url(
+        this: &StorageFileApi,
+        path: &str,
+    ) -> Result<JsValue, JsValue>;
+
+    /// # Upload to a signed URL
+    ///
+    /// Uploads a file with a token generated from `create_signed_upload_url`.
+    ///
+    #[wasm_bindgen(method, catch, js_name = uploadToSignedUrl)]
+    pub async fn upload_to_signed_url(
+        this: &StorageFileApi,
+        path: &str,
+        token: &str,
+        file_body: JsValue,
+        file_options: JsValue,
+    ) -> Result<JsValue, JsValue>;
 }
@@ -0,0 +1,214 @@
+//! Verify and resend one-time passwords through [`Auth`].
+
+use js_sys::Object;
+use wasm_bindgen::JsValue;
+
+use crate::error::set;
+use crate::Auth;
+
+/// Type of a one-time password sent by email
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailOtpType {
+    Email,
+    Signup,
+    Invite,
+    Magiclink,
+    Recovery,
+    EmailChange,
+}
+
+impl EmailOtpType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EmailOtpType::Email => "email",
+            EmailOtpType::Signup => "signup",
+            EmailOtpType::Invite => "invite",
+            EmailOtpType::Magiclink => "magiclink",
+            EmailOtpType::Recovery => "recovery",
+            EmailOtpType::EmailChange => "email_change",
+        }
+    }
+}
+
+/// Type of a one-time password sent by SMS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MobileOtpType {
+    Sms,
+    PhoneChange,
+}
+
+impl MobileOtpType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MobileOtpType::Sms => "sms",
+            MobileOtpType::PhoneChange => "phone_change",
+        }
+    }
+}
+
+/// Parameters of [`Auth::verify_otp`]
+#[derive(Debug, Clone)]
+pub struct VerifyOtpParams {
+    key: &'static str,
+    value: String,
+    token: Option<String>,
+    otp_type: &'static str,
+    redirect_to: Option<String>,
+    captcha_token: Option<String>,
+}
+
+impl VerifyOtpParams {
+    /// Verify a code sent to an email address.
+    pub fn email(email: &str, token: &str, otp_type: EmailOtpType) -> Self {
+        Self::new("email", email, Some(token), otp_type.as_str())
+    }
+
+    /// Verify a code sent to a phone number.
+    pub fn phone(phone: &str, token: &str, otp_type: MobileOtpType) -> Self {
+        Self::new("phone", phone, Some(token), otp_type.as_str())
+    }
+
+    /// Verify the token hash of an email link.
+    pub fn token_hash(token_hash: &str, otp_type: EmailOtpType) -> Self {
+        Self::new("token_hash", token_hash, None, otp_type.as_str())
+    }
+
+    fn new(key: &'static str, value: &str, token: Option<&str>, otp_type: &'static str) -> Self {
+        Self {
+            key,
+            value: value.to_string(),
+            token: token.map(str::to_string),
+            otp_type,
+            redirect_to: None,
+            captcha_token: None,
+        }
+    }
+
+    /// URL to redirect to after the email link is verified.
+    pub fn redirect_to(mut self, url: &str) -> Self {
+        self.redirect_to = Some(url.to_string());
+        self
+    }
+
+    pub fn captcha_token(mut self, captcha_token: &str) -> Self {
+        self.captcha_token = Some(captcha_token.to_string());
+        self
+    }
+}
+
+impl From<VerifyOtpParams> for JsValue {
+    fn from(params: VerifyOtpParams) -> Self {
+        let object = Object::new();
+        set(&object, params.key, &JsValue::from_str(&params.value));
+        if let Some(token) = &params.token {
+            set(&object, "token", &JsValue::from_str(token));
+        }
+        set(&object, "type", &JsValue::from_str(params.otp_type));
+        let options = Object::new();
+        if let Some(redirect_to) = &params.redirect_to {
+            set(&options, "redirectTo", &JsValue::from_str(redirect_to));
+        }
+        if let Some(captcha_token) = &params.captcha_token {
+            set(&options, "captchaToken", &JsValue::from_str(captcha_token));
+        }
+        set(&object, "options", &options);
+        object.into()
+    }
+}
+
+/// Parameters of [`Auth::resend`]
+#[derive(Debug, Clone)]
+pub struct ResendParams {
+    key: &'static str,
+    value: String,
+    otp_type: &'static str,
+    email_redirect_to: Option<String>,
+    captcha_token: Option<String>,
+}
+
+impl ResendParams {
+    /// Resend the signup confirmation email.
+    pub fn signup(email: &str) -> Self {
+        Self::new("email", email, "signup")
+    }
+
+    /// Resend the email change confirmation email.
+    pub fn email_change(email: &str) -> Self {
+        Self::new("email", email, "email_change")
+    }
+
+    /// Resend the signup confirmation SMS.
+    pub fn sms(phone: &str) -> Self {
+        Self::new("phone", phone, "sms")
+    }
+
+    /// Resend the phone change confirmation SMS.
+    pub fn phone_change(phone: &str) -> Self {
+        Self::new("phone", phone, "phone_change")
+    }
+
+    fn new(key: &'static str, value: &str, otp_type: &'static str) -> Self {
+        Self {
+            key,
+            value: value.to_string(),
+            otp_type,
+            email_redirect_to: None,
+            captcha_token: None,
+        }
+    }
+
+    /// URL to redirect to from the confirmation email.
+    pub fn email_redirect_to(mut self, url: &str) -> Self {
+        self.email_redirect_to = Some(url.to_string());
+        self
+    }
+
+    pub fn captcha_token(mut self, captcha_token: &str) -> Self {
+        self.captcha_token = Some(captcha_token.to_string());
+        self
+    }
+}
+
+impl From<ResendParams> for JsValue {
+    fn from(params: ResendParams) -> Self {
+        let object = Object::new();
+        set(&object, params.key, &JsValue::from_str(&params.value));
+        set(&object, "type", &JsValue::from_str(params.otp_type));
+        let options = Object::new();
+        if let Some(email_redirect_to) = &params.email_redirect_to {
+            set(
+                &options,
+                "emailRedirectTo",
+                &JsValue::from_str(email_redirect_to),
+            );
+        }
+        if let Some(captcha_token) = &params.captcha_token {
+            set(&options, "captchaToken", &JsValue::from_str(captcha_token));
+        }
+        set(&object, "options", &options);
+        object.into()
+    }
+}
+
+impl Auth {
+    /// # Verify and log in through OTP
+    ///
+    /// Verifies a code or token hash and creates a session.
+    ///
+    /// ```ignore
+    /// let params = VerifyOtpParams::email("user@example.com", "123456", EmailOtpType::Email);
+    /// let res = client.auth().verify_otp(params).await;
+    /// ```
+    ///
+    pub async fn verify_otp(&self, params: VerifyOtpParams) -> Result<JsValue, JsValue> {
+        self.verify_otp_js(params.into()).await
+    }
+
+    /// # Resend an OTP
+    ///
+    /// Resends a signup confirmation, email change or phone change OTP.
+    ///
+    pub async fn resend(&self, params: ResendParams) -> Result<JsValue, JsValue> {
+        self.resend_js(params.into()).await
+    }
+}
@@ -0,0 +1,546 @@
+//! Rust-side query builder with typed filter values.
+
+use js_sys::Object;
+use wasm_bindgen::JsValue;
+
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+
+use crate::error::set;
+use crate::{Database, Expr, SupabaseClient, SupabaseError};
+
+/// Largest integer a JavaScript number represents exactly.
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// Value compared against a column
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl FilterValue {
+    /// Convert into the value passed to supabase-js.
+    pub fn to_js(&self) -> JsValue {
+        match self {
+            FilterValue::Null => JsValue::NULL,
+            FilterValue::Bool(value) => JsValue::from_bool(*value),
+            FilterValue::Int(value) if value.abs() <= MAX_SAFE_INTEGER => {
+                JsValue::from_f64(*value as f64)
+            }
+            // PostgREST parses the value from the URL, so a string keeps every digit
+            FilterValue::Int(value) => JsValue::from_str(&value.to_string()),
+            FilterValue::Float(value) => JsValue::from_f64(*value),
+            FilterValue::Text(value) => JsValue::from_str(value),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for FilterValue {
+            fn from(value: $t) -> Self {
+                FilterValue::Int(value as i64)
+            }
+        })*
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, u8, u16, u32);
+
+impl From<u64> for FilterValue {
+    fn from(value: u64) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => FilterValue::Int(value),
+            Err(_) => FilterValue::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<f32> for FilterValue {
+    fn from(value: f32) -> Self {
+        FilterValue::Float(value as f64)
+    }
+}
+
+impl From<f64> for FilterValue {
+    fn from(value: f64) -> Self {
+        FilterValue::Float(value)
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(value: bool) -> Self {
+        FilterValue::Bool(value)
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(value: &str) -> Self {
+        FilterValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(value: String) -> Self {
+        FilterValue::Text(value)
+    }
+}
+
+impl From<&String> for FilterValue {
+    fn from(value: &String) -> Self {
+        FilterValue::Text(value.clone())
+    }
+}
+
+impl<T: Into<FilterValue>> From<Option<T>> for FilterValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(FilterValue::Null, Into::into)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for FilterValue {
+    fn from(value: chrono::NaiveDate) -> Self {
+        FilterValue::Text(value.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for FilterValue {
+    fn from(value: chrono::NaiveDateTime) -> Self {
+        FilterValue::Text(value.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for FilterValue
+where
+    Tz::Offset: std::fmt::Display,
+{
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        FilterValue::Text(value.to_rfc3339())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for FilterValue {
+    fn from(value: time::Date) -> Self {
+        FilterValue::Text(value.to_string())
+    }
+}
+
+/// ISO 8601 date and time, e.g. `2024-01-05T09:05:00.0`.
+#[cfg(feature = "time")]
+const DATE_TIME: &[time::format_description::BorrowedFormatItem<'static>] =
+    time::macros::format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]");
+
+/// ISO 8601 date and time with an offset including seconds, e.g. `+01:00:30`.
+#[cfg(feature = "time")]
+const DATE_TIME_OFFSET: &[time::format_description::BorrowedFormatItem<'static>] = time::macros::format_description!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory]:[offset_minute]:[offset_second]"
+);
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for FilterValue {
+    fn from(value: time::PrimitiveDateTime) -> Self {
+        let text = value
+            .format(DATE_TIME)
+            .unwrap_or_else(|_| value.to_string());
+        FilterValue::Text(text)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for FilterValue {
+    fn from(value: time::OffsetDateTime) -> Self {
+        // RFC 3339 can't represent every value, e.g. negative years or offsets with seconds
+        let text = value
+            .format(&time::format_description::well_known::Rfc3339)
+            .or_else(|_| value.format(DATE_TIME_OFFSET))
+            .unwrap_or_else(|_| value.to_string());
+        FilterValue::Text(text)
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl From<uuid::Uuid> for FilterValue {
+    fn from(value: uuid::Uuid) -> Self {
+        FilterValue::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Compare {
+    Eq,
+    Neq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    Is,
+}
+
+impl Compare {
+    /// Operator sent for `value` and whether it is negated. `eq.null` compares with
+    /// the string "null", so equality with null is lowered to IS.
+    fn lower(self, value: &FilterValue) -> (Compare, bool) {
+        match (self, value) {
+            (Compare::Eq, FilterValue::Null) => (Compare::Is, false),
+            (Compare::Neq, FilterValue::Null) => (Compare::Is, true),
+            (op, _) => (op, false),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pattern {
+    Like,
+    Ilike,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Compare {
+        column: String,
+        op: Compare,
+        value: FilterValue,
+    },
+    Pattern {
+        column: String,
+        op: Pattern,
+        pattern: String,
+    },
+    In {
+        column: String,
+        values: Vec<FilterValue>,
+    },
+    Expr(Expr),
+}
+
+/// Query built in Rust
+///
+/// Every method returns a new builder, so a base query can be shared between variations.
+/// The query is lowered into a [`Database`] chain by [`QueryBuilder::build`].
+///
+/// ```ignore
+/// let adults = QueryBuilder::from("users").select("id, name").gte("age", 18);
+/// let named_bob = adults.eq("name", "Bob");
+/// let res = named_bob.execute(&client).await?;
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryBuilder {
+    table: String,
+    columns: Option<String>,
+    filters: Vec<Filter>,
+    order: Vec<(String, bool)>,
+    limit: Option<u32>,
+    range: Option<(u32, u32)>,
+}
+
+impl QueryBuilder {
+    /// Query a table or view.
+    pub fn from(table: &str) -> Self {
+        Self {
+            table: table.to_string(),
+            columns: None,
+            filters: Vec::new(),
+            order: Vec::new(),
+            limit: None,
+            range: None,
+        }
+    }
+
+    /// Columns to retrieve, `*` by default.
+    pub fn select(&self, columns: &str) -> Self {
+        let mut query = self.clone();
+        query.columns = Some(columns.to_string());
+        query
+    }
+
+    /// Match only rows where column is equal to value, or IS NULL for `None`.
+    pub fn eq(&self, column: &str, value: impl Into<FilterValue>) -> Self {
+        self.compare(column, Compare::Eq, value.into())
+    }
+
+    /// Match only rows where column is not equal to value, or IS NOT NULL for `None`.
+    pub fn neq(&self, column: &str, value: impl Into<FilterValue>) -> Self {
+        self.compare(column, Compare::Neq, value.into())
+    }
+
+    /// Match only rows where column is greater than value.
+    pub fn gt(&self, column: &str, value: impl Into<FilterValue>) -> Self {
+        self.compare(column, Compare::Gt, value.into())
+    }
+
+    /// Match only rows where column is greater than or equal to value.
+    pub fn gte(&self, column: &str, value: impl Into<FilterValue>) -> Self {
+        self.compare(column, Compare::Gte, value.into())
+    }
+
+    /// Match only rows where column is less than value.
+    pub fn lt(&self, column: &str, value: impl Into<FilterValue>) -> Self {
+        self.compare(column, Compare::Lt, value.into())
+    }
+
+    /// Match only rows where column is less than or equal to value.
+    pub fn lte(&self, column: &str, value: impl Into<FilterValue>) -> Self {
+        self.compare(column, Compare::Lte, value.into())
+    }
+
+    /// Match only rows where column IS value, i.e. `None`, `true` or `false`.
+    pub fn is(&self, column: &str, value: Option<bool>) -> Self {
+        self.compare(column, Compare::Is, value.into())
+    }
+
+    /// Match only rows where column matches pattern case-sensitively.
+    pub fn like(&self, column: &str, pattern: &str) -> Self {
+        self.pattern(column, Pattern::Like, pattern)
+    }
+
+    /// Match only rows where column matches pattern case-insensitively.
+    pub fn ilike(&self, column: &str, pattern: &str) -> Self {
+        self.pattern(column, Pattern::Ilike, pattern)
+    }
+
+    /// Match only rows where column is included in the values.
+    pub fn r#in<T: Into<FilterValue>>(
+        &self,
+        column: &str,
+        values: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.with_filter(Filter::In {
+            column: column.to_string(),
+            values: values.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// Match only rows which satisfy the expression, see [`Expr`].
+    pub fn filter_by(&self, expr: Expr) -> Self {
+        self.with_filter(Filter::Expr(expr))
+    }
+
+    /// Order the result by column.
+    pub fn order(&self, column: &str, ascending: bool) -> Self {
+        let mut query = self.clone();
+        query.order.push((column.to_string(), ascending));
+        query
+    }
+
+    /// Limit the result to count rows.
+    pub fn limit(&self, count: u32) -> Self {
+        let mut query = self.clone();
+        query.limit = Some(count);
+        query
+    }
+
+    /// Limit the result to rows from and to inclusively.
+    pub fn range(&self, from: u32, to: u32) -> Self {
+        let mut query = self.clone();
+        query.range = Some((from, to));
+        query
+    }
+
+    /// # Lower into a `Database` query
+    ///
+    /// Fails with [`SupabaseError::InvalidFilter`] for an expression that never matches,
+    /// see [`Database::filter_by`].
+    ///
+    pub fn build(&self, client: &SupabaseClient) -> Result<Database, SupabaseError> {
+        let query = client.from(&self.table).select_(self.columns.as_deref());
+        self.apply(query)
+    }
+
+    /// # Apply filters and modifiers
+    ///
+    /// Apply the filters and modifiers of this builder to another query, e.g. an update.
+    ///
+    /// ```ignore
+    /// let query = QueryBuilder::from("countries").eq("id", 1);
+    /// query.apply(client.from("countries").delete_())?.execute().await?;
+    /// ```
+    ///
+    pub fn apply(&self, mut query: Database) -> Result<Database, SupabaseError> {
+        for filter in &self.filters {
+            query = match filter {
+                Filter::Compare { column, op, value } => {
+                    let (op, negated) = op.lower(value);
+                    let value = value.to_js();
+                    match op {
+                        Compare::Is if negated => query.not_(column, "is", &value),
+                        Compare::Eq => query.eq_(column, &value),
+                        Compare::Neq => query.neq_(column, &value),
+                        Compare::Gt => query.gt_(column, &value),
+                        Compare::Gte => query.gte_(column, &value),
+                        Compare::Lt => query.lt_(column, &value),
+                        Compare::Lte => query.lte_(column, &value),
+                        Compare::Is => query.is_(column, &value),
+                    }
+                }
+                Filter::Pattern {
+                    column,
+                    op,
+                    pattern,
+                } => match op {
+                    Pattern::Like => query.like_(column, pattern),
+                    Pattern::Ilike => query.ilike_(column, pattern),
+                },
+                Filter::In { column, values } => {
+                    query.r#in_(column, values.iter().map(FilterValue::to_js).collect())
+                }
+                Filter::Expr(expr) => query.filter_by(expr)?,
+            };
+        }
+        for (column, ascending) in &self.order {
+            let options = Object::new();
+            set(&options, "ascending", &JsValue::from_bool(*ascending));
+            query = query.order_(column, options.into());
+        }
+        if let Some(count) = self.limit {
+            query = query.limit_(count);
+        }
+        if let Some((from, to)) = self.range {
+            query = query.range_(from, to);
+        }
+        Ok(query)
+    }
+
+    /// # Execute the query
+    ///
+    pub async fn execute(&self, client: &SupabaseClient) -> Result<JsValue, SupabaseError> {
+        self.build(client)?.execute().await
+    }
+
+    /// # Execute the query as `Vec<T>`
+    ///
+    #[cfg(feature = "serde")]
+    pub async fn execute_as<T: DeserializeOwned>(
+        &self,
+        client: &SupabaseClient,
+    ) -> Result<Vec<T>, SupabaseError> {
+        self.build(client)?.execute_as().await
+    }
+
+    fn compare(&self, column: &str, op: Compare, value: FilterValue) -> Self {
+        self.with_filter(Filter::Compare {
+            column: column.to_string(),
+            op,
+            value,
+        })
+    }
+
+    fn pattern(&self, column: &str, op: Pattern, pattern: &str) -> Self {
+        self.with_filter(Filter::Pattern {
+            column: column.to_string(),
+            op,
+            pattern: pattern.to_string(),
+        })
+    }
+
+    fn with_filter(&self, filter: Filter) -> Self {
+        let mut query = self.clone();
+        query.filters.push(filter);
+        query
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_integers() {
+        assert_eq!(FilterValue::from(-3i8), FilterValue::Int(-3));
+        assert_eq!(FilterValue::from(7u32), FilterValue::Int(7));
+        assert_eq!(FilterValue::from(i64::MAX), FilterValue::Int(i64::MAX));
+    }
+
+    #[test]
+    fn converts_large_u64_to_text() {
+        assert_eq!(FilterValue::from(42u64), FilterValue::Int(42));
+        assert_eq!(
+            FilterValue::from(u64::MAX),
+            FilterValue::Text("18446744073709551615".to_string())
+        );
+    }
+
+    #[test]
+    fn converts_options_and_text() {
+        assert_eq!(FilterValue::from(None::<i32>), FilterValue::Null);
+        assert_eq!(FilterValue::from(Some(true)), FilterValue::Bool(true));
+        assert_eq!(FilterValue::from("a"), FilterValue::Text("a".to_string()));
+        assert_eq!(FilterValue::from(1.5f32), FilterValue::Float(1.5));
+    }
+
+    #[test]
+    fn lowers_null_equality_to_is() {
+        assert_eq!(Compare::Eq.lower(&FilterValue::Null), (Compare::Is, false));
+        assert_eq!(Compare::Neq.lower(&FilterValue::Null), (Compare::Is, true));
+        assert_eq!(
+            Compare::Eq.lower(&FilterValue::Int(1)),
+            (Compare::Eq, false)
+        );
+        assert_eq!(Compare::Gt.lower(&FilterValue::Null), (Compare::Gt, false));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn formats_chrono() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let date = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        assert_eq!(
+            FilterValue::from(date),
+            FilterValue::Text("2024-01-05".to_string())
+        );
+        let date_time = date.and_hms_milli_opt(9, 5, 0, 250).unwrap();
+        assert_eq!(
+            FilterValue::from(date_time),
+            FilterValue::Text("2024-01-05T09:05:00.250".to_string())
+        );
+        assert_eq!(
+            FilterValue::from(Utc.from_utc_datetime(&date.and_hms_opt(9, 5, 0).unwrap())),
+            FilterValue::Text("2024-01-05T09:05:00+00:00".to_string())
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn formats_time() {
+        use time::macros::{date, datetime};
+
+        assert_eq!(
+            FilterValue::from(date!(2024 - 01 - 05)),
+            FilterValue::Text("2024-01-05".to_string())
+        );
+        assert_eq!(
+            FilterValue::from(datetime!(2024-01-05 9:05)),
+            FilterValue::Text("2024-01-05T09:05:00.0".to_string())
+        );
+        assert_eq!(
+            FilterValue::from(datetime!(2024-01-05 9:05 +1)),
+            FilterValue::Text("2024-01-05T09:05:00+01:00".to_string())
+        );
+        assert_eq!(
+            FilterValue::from(datetime!(2024-01-05 9:05 +1:00:30)),
+            FilterValue::Text("2024-01-05T09:05:00.0+01:00:30".to_string())
+        );
+        assert_eq!(
+            FilterValue::from(datetime!(-0001-01-05 9:05 UTC)),
+            FilterValue::Text("-0001-01-05T09:05:00.0+00:00:00".to_string())
+        );
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn formats_uuid() {
+        let uuid = uuid::Uuid::from_u128(0x67e5504410b1426f9247bb680e5fe0c8);
+        assert_eq!(
+            FilterValue::from(uuid),
+            FilterValue::Text("67e55044-10b1-426f-9247-bb680e5fe0c8".to_string())
+        );
+    }
+}
@@ -0,0 +1,609 @@
+//! Channel configuration, broadcast messages, presence and database changes for
+//! [`RealtimeChannel`].
+
+#[cfg(feature = "serde")]
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use js_sys::Object;
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+#[cfg(feature = "serde")]
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::error::set;
+#[cfg(feature = "serde")]
+use crate::error::{from_value, get, to_value};
+use crate::{RealtimeChannel, SupabaseClient, SupabaseError};
+
+/// Options of [`SupabaseClient::channel_with_config`]
+#[derive(Debug, Clone, Default)]
+pub struct ChannelConfig {
+    broadcast_self: Option<bool>,
+    broadcast_ack: Option<bool>,
+    presence_key: Option<String>,
+}
+
+impl ChannelConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Receive your own broadcast messages.
+    pub fn broadcast_self(mut self, broadcast_self: bool) -> Self {
+        self.broadcast_self = Some(broadcast_self);
+        self
+    }
+
+    /// Wait for the server to acknowledge broadcast messages.
+    pub fn broadcast_ack(mut self, broadcast_ack: bool) -> Self {
+        self.broadcast_ack = Some(broadcast_ack);
+        self
+    }
+
+    /// Key to track presence under, a random UUID by default.
+    pub fn presence_key(mut self, presence_key: &str) -> Self {
+        self.presence_key = Some(presence_key.to_string());
+        self
+    }
+}
+
+impl From<ChannelConfig> for JsValue {
+    fn from(config: ChannelConfig) -> Self {
+        let broadcast = Object::new();
+        if let Some(broadcast_self) = config.broadcast_self {
+            set(&broadcast, "self", &JsValue::from_bool(broadcast_self));
+        }
+        if let Some(broadcast_ack) = config.broadcast_ack {
+            set(&broadcast, "ack", &JsValue::from_bool(broadcast_ack));
+        }
+        let inner = Object::new();
+        set(&inner, "broadcast", &broadcast);
+        if let Some(presence_key) = &config.presence_key {
+            let presence = Object::new();
+            set(&presence, "key", &JsValue::from_str(presence_key));
+            set(&inner, "presence", &presence);
+        }
+        let opts = Object::new();
+        set(&opts, "config", &inner);
+        opts.into()
+    }
+}
+
+/// Status of a sent message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SendStatus {
+    Ok,
+    TimedOut,
+    RateLimited,
+    Error,
+    /// Status unknown to this crate.
+    Other(String),
+}
+
+impl From<&str> for SendStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "ok" => SendStatus::Ok,
+            "timed out" => SendStatus::TimedOut,
+            "rate limited" => SendStatus::RateLimited,
+            "error" => SendStatus::Error,
+            status => SendStatus::Other(status.to_string()),
+        }
+    }
+}
+
+/// Status of a channel subscription
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscribeStatus {
+    Subscribed,
+    TimedOut,
+    Closed,
+    ChannelError,
+    /// Status unknown to this crate.
+    Other(String),
+}
+
+impl From<&str> for SubscribeStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "SUBSCRIBED" => SubscribeStatus::Subscribed,
+            "TIMED_OUT" => SubscribeStatus::TimedOut,
+            "CLOSED" => SubscribeStatus::Closed,
+            "CHANNEL_ERROR" => SubscribeStatus::ChannelError,
+            status => SubscribeStatus::Other(status.to_string()),
+        }
+    }
+}
+
+/// Presence event to listen to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceEvent {
+    /// Presence state was synchronized.
+    Sync,
+    /// A client started tracking presence.
+    Join,
+    /// A client stopped tracking presence.
+    Leave,
+}
+
+impl PresenceEvent {
+    /// Event name of the `on("presence", { event }, callback)` filter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PresenceEvent::Sync => "sync",
+            PresenceEvent::Join => "join",
+            PresenceEvent::Leave => "leave",
+        }
+    }
+}
+
+/// Presence change delivered to [`RealtimeChannel::on_presence`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum PresenceChange<T> {
+    /// Read the new state with [`RealtimeChannel::presence_state_as`].
+    Sync,
+    Join {
+        key: String,
+        current_presences: Vec<T>,
+        new_presences: Vec<T>,
+    },
+    Leave {
+        key: String,
+        current_presences: Vec<T>,
+        left_presences: Vec<T>,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl<T: DeserializeOwned> PresenceChange<T> {
+    fn from_js(event: PresenceEvent, payload: JsValue) -> Result<Self, SupabaseError> {
+        if event == PresenceEvent::Sync {
+            return Ok(PresenceChange::Sync);
+        }
+        let key = get(&payload, "key").as_string().unwrap_or_default();
+        let current_presences = from_value(get(&payload, "currentPresences"))?;
+        Ok(match event {
+            PresenceEvent::Sync => PresenceChange::Sync,
+            PresenceEvent::Join => PresenceChange::Join {
+                key,
+                current_presences,
+                new_presences: from_value(get(&payload, "newPresences"))?,
+            },
+            PresenceEvent::Leave => PresenceChange::Leave {
+                key,
+                current_presences,
+                left_presences: from_value(get(&payload, "leftPresences"))?,
+            },
+        })
+    }
+}
+
+/// Database change to listen to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum PostgresChangesEvent {
+    #[cfg_attr(feature = "serde", serde(rename = "*"))]
+    All,
+    #[cfg_attr(feature = "serde", serde(rename = "INSERT"))]
+    Insert,
+    #[cfg_attr(feature = "serde", serde(rename = "UPDATE"))]
+    Update,
+    #[cfg_attr(feature = "serde", serde(rename = "DELETE"))]
+    Delete,
+}
+
+impl PostgresChangesEvent {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PostgresChangesEvent::All => "*",
+            PostgresChangesEvent::Insert => "INSERT",
+            PostgresChangesEvent::Update => "UPDATE",
+            PostgresChangesEvent::Delete => "DELETE",
+        }
+    }
+}
+
+/// Filter of `postgres_changes` listeners
+///
+/// ```ignore
+/// let filter = PostgresChangesFilter::new(PostgresChangesEvent::Insert)
+///     .table("messages")
+///     .eq("room_id", 1);
+/// client
+///     .channel("room-1")
+///     .on("postgres_changes", &filter.into(), &callback)
+///     .subscribe(None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PostgresChangesFilter {
+    event: PostgresChangesEvent,
+    schema: String,
+    table: Option<String>,
+    filter: Option<String>,
+}
+
+impl PostgresChangesFilter {
+    /// Listen to `event` in the `public` schema.
+    pub fn new(event: PostgresChangesEvent) -> Self {
+        Self {
+            event,
+            schema: "public".to_string(),
+            table: None,
+            filter: None,
+        }
+    }
+
+    /// Schema to listen to, `*` for all schemas.
+    pub fn schema(mut self, schema: &str) -> Self {
+        self.schema = schema.to_string();
+        self
+    }
+
+    pub fn table(mut self, table: &str) -> Self {
+        self.table = Some(table.to_string());
+        self
+    }
+
+    /// Raw `column=op.value` filter, e.g. `id=eq.1`. Only one filter is supported.
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    /// Only rows where column is equal to value.
+    pub fn eq(self, column: &str, value: impl Display) -> Self {
+        self.op(column, "eq", value)
+    }
+
+    /// Only rows where column is not equal to value.
+    pub fn neq(self, column: &str, value: impl Display) -> Self {
+        self.op(column, "neq", value)
+    }
+
+    /// Only rows where column is greater than value.
+    pub fn gt(self, column: &str, value: impl Display) -> Self {
+        self.op(column, "gt", value)
+    }
+
+    /// Only rows where column is greater than or equal to value.
+    pub fn gte(self, column: &str, value: impl Display) -> Self {
+        self.op(column, "gte", value)
+    }
+
+    /// Only rows where column is less than value.
+    pub fn lt(self, column: &str, value: impl Display) -> Self {
+        self.op(column, "lt", value)
+    }
+
+    /// Only rows where column is less than or equal to value.
+    pub fn lte(self, column: &str, value: impl Display) -> Self {
+        self.op(column, "lte", value)
+    }
+
+    /// Only rows where column is included in values.
+    pub fn r#in<T: Display>(self, column: &str, values: &[T]) -> Self {
+        let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+        self.op(column, "in", format!("({})", values.join(",")))
+    }
+
+    fn op(self, column: &str, op: &str, value: impl Display) -> Self {
+        self.filter(&format!("{}={}.{}", column, op, value))
+    }
+}
+
+impl From<PostgresChangesFilter> for JsValue {
+    fn from(filter: PostgresChangesFilter) -> Self {
+        let object = Object::new();
+        set(&object, "event", &JsValue::from_str(filter.event.as_str()));
+        set(&object, "schema", &JsValue::from_str(&filter.schema));
+        if let Some(table) = &filter.table {
+            set(&object, "table", &JsValue::from_str(table));
+        }
+        if let Some(filter) = &filter.filter {
+            set(&object, "filter", &JsValue::from_str(filter));
+        }
+        object.into()
+    }
+}
+
+/// Database change delivered to [`RealtimeChannel::on_postgres_changes`]
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PostgresChangesPayload<T> {
+    pub schema: String,
+    pub table: String,
+    pub commit_timestamp: String,
+    /// `Insert`, `Update` or `Delete`.
+    pub event_type: PostgresChangesEvent,
+    /// New row, `None` for deletes.
+    pub new: Option<T>,
+    /// Old row, `None` for inserts. Without `REPLICA IDENTITY FULL` on the table the
+    /// old row holds only the primary key, which usually doesn't decode as `T`,
+    /// so it is `None` as well and only available in `old_raw`.
+    pub old: Option<T>,
+    /// Old row as sent by Realtime.
+    pub old_raw: JsValue,
+    pub errors: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+impl<T: DeserializeOwned> PostgresChangesPayload<T> {
+    fn from_js(payload: JsValue) -> Result<Self, SupabaseError> {
+        let old_raw = get(&payload, "old");
+        Ok(Self {
+            schema: from_value(get(&payload, "schema"))?,
+            table: from_value(get(&payload, "table"))?,
+            commit_timestamp: from_value(get(&payload, "commit_timestamp"))?,
+            event_type: from_value(get(&payload, "eventType"))?,
+            new: record(get(&payload, "new"))?,
+            old: record(old_raw.clone()).unwrap_or(None),
+            old_raw,
+            errors: from_value::<Option<Vec<String>>>(get(&payload, "errors"))?.unwrap_or_default(),
+        })
+    }
+}
+
+/// Deserialize a row, treating the empty object sent for missing rows as `None`.
+#[cfg(feature = "serde")]
+fn record<T: DeserializeOwned>(row: JsValue) -> Result<Option<T>, SupabaseError> {
+    if !row.is_object() || Object::keys(row.unchecked_ref::<Object>()).length() == 0 {
+        return Ok(None);
+    }
+    from_value(row).map(Some)
+}
+
+impl SupabaseClient {
+    /// # Create a channel with options
+    ///
+    /// ```ignore
+    /// let channel = client.channel_with_config("room-1", ChannelConfig::new().broadcast_self(true));
+    /// ```
+    ///
+    pub fn channel_with_config(&self, name: &str, config: ChannelConfig) -> RealtimeChannel {
+        self.channel_js(name, config.into())
+    }
+
+    /// # Retrieve all channels
+    ///
+    pub fn get_channels(&self) -> Vec<RealtimeChannel> {
+        self.get_channels_js()
+            .iter()
+            .map(|channel| channel.unchecked_into())
+            .collect()
+    }
+}
+
+impl RealtimeChannel {
+    /// # Subscribe to a channel with status
+    ///
+    /// The callback is called with every status change of the subscription and the error
+    /// reported with `CHANNEL_ERROR` or `TIMED_OUT`. It lives as long as the page.
+    ///
+    /// ```ignore
+    /// client.channel("room-1").subscribe_with_status(|status, error| {
+    ///     if status == SubscribeStatus::ChannelError {
+    ///         // ...
+    ///     }
+    /// });
+    /// ```
+    ///
+    pub fn subscribe_with_status<F>(&self, mut callback: F) -> RealtimeChannel
+    where
+        F: FnMut(SubscribeStatus, Option<SupabaseError>) + 'static,
+    {
+        let closure: Closure<dyn FnMut(JsValue, JsValue)> =
+            Closure::new(move |status: JsValue, error: JsValue| {
+                let status = SubscribeStatus::from(status.as_string().unwrap_or_default().as_str());
+                let error = if error.is_null() || error.is_undefined() {
+                    None
+                } else {
+                    Some(SupabaseError::from_js(error))
+                };
+                callback(status, error);
+            });
+        let channel = self.subscribe(Some(&closure));
+        closure.forget();
+        channel
+    }
+
+    /// # Send a broadcast message
+    ///
+    /// ```ignore
+    /// let status = channel.send_broadcast("cursor-pos", &payload).await?;
+    /// ```
+    ///
+    pub async fn send_broadcast(
+        &self,
+        event: &str,
+        payload: &JsValue,
+    ) -> Result<SendStatus, SupabaseError> {
+        let args = Object::new();
+        set(&args, "type", &JsValue::from_str("broadcast"));
+        set(&args, "event", &JsValue::from_str(event));
+        set(&args, "payload", payload);
+        let status = self.send(args.into(), JsValue::UNDEFINED).await?;
+        Ok(SendStatus::from(
+            status.as_string().unwrap_or_default().as_str(),
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl RealtimeChannel {
+    /// # Send a serialized broadcast message
+    ///
+    pub async fn send_broadcast_as<T: Serialize + ?Sized>(
+        &self,
+        event: &str,
+        payload: &T,
+    ) -> Result<SendStatus, SupabaseError> {
+        self.send_broadcast(event, &to_value(payload)?).await
+    }
+
+    /// # Listen to broadcast messages
+    ///
+    /// Deserializes the payload of every `event` message into `T`. The callback lives as long
+    /// as the page.
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct Cursor {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    /// client
+    ///     .channel("room-1")
+    ///     .on_broadcast("cursor-pos", |cursor: Result<Cursor, SupabaseError>| {
+    ///         // ...
+    ///     })
+    ///     .subscribe(None);
+    /// ```
+    ///
+    pub fn on_broadcast<T, F>(&self, event: &str, callback: F) -> RealtimeChannel
+    where
+        T: DeserializeOwned,
+        F: Fn(Result<T, SupabaseError>) + 'static,
+    {
+        let (channel, closure) = self.listen_broadcast(event, callback);
+        closure.forget();
+        channel
+    }
+
+    pub(crate) fn listen_broadcast<T, F>(
+        &self,
+        event: &str,
+        callback: F,
+    ) -> (RealtimeChannel, Closure<dyn Fn(JsValue)>)
+    where
+        T: DeserializeOwned,
+        F: Fn(Result<T, SupabaseError>) + 'static,
+    {
+        let filter = Object::new();
+        set(&filter, "event", &JsValue::from_str(event));
+        let closure: Closure<dyn Fn(JsValue)> = Closure::new(move |message: JsValue| {
+            callback(from_value(get(&message, "payload")));
+        });
+        (self.on("broadcast", &filter, &closure), closure)
+    }
+
+    /// # Listen to database changes
+    ///
+    /// Deserializes changed rows into `T`. The callback lives as long as the page.
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct Message {
+    ///     id: i64,
+    ///     body: String,
+    /// }
+    /// client
+    ///     .channel("room-1")
+    ///     .on_postgres_changes(
+    ///         PostgresChangesFilter::new(PostgresChangesEvent::Insert).table("messages"),
+    ///         |payload: Result<PostgresChangesPayload<Message>, SupabaseError>| {
+    ///             // ...
+    ///         },
+    ///     )
+    ///     .subscribe(None);
+    /// ```
+    ///
+    pub fn on_postgres_changes<T, F>(
+        &self,
+        filter: PostgresChangesFilter,
+        callback: F,
+    ) -> RealtimeChannel
+    where
+        T: DeserializeOwned,
+        F: Fn(Result<PostgresChangesPayload<T>, SupabaseError>) + 'static,
+    {
+        let (channel, closure) = self.listen_postgres_changes(filter, callback);
+        closure.forget();
+        channel
+    }
+
+    pub(crate) fn listen_postgres_changes<T, F>(
+        &self,
+        filter: PostgresChangesFilter,
+        callback: F,
+    ) -> (RealtimeChannel, Closure<dyn Fn(JsValue)>)
+    where
+        T: DeserializeOwned,
+        F: Fn(Result<PostgresChangesPayload<T>, SupabaseError>) + 'static,
+    {
+        let closure: Closure<dyn Fn(JsValue)> = Closure::new(move |payload: JsValue| {
+            callback(PostgresChangesPayload::from_js(payload));
+        });
+        (
+            self.on("postgres_changes", &filter.into(), &closure),
+            closure,
+        )
+    }
+
+    /// # Track serialized presence
+    ///
+    pub async fn track_as<T: Serialize + ?Sized>(
+        &self,
+        payload: &T,
+    ) -> Result<SendStatus, SupabaseError> {
+        let status = self.track(&to_value(payload)?, JsValue::UNDEFINED).await?;
+        Ok(SendStatus::from(
+            status.as_string().unwrap_or_default().as_str(),
+        ))
+    }
+
+    /// # Retrieve presence state as `T`
+    ///
+    /// Returns the payloads tracked under each presence key.
+    ///
+    pub fn presence_state_as<T: DeserializeOwned>(
+        &self,
+    ) -> Result<HashMap<String, Vec<T>>, SupabaseError> {
+        from_value(self.presence_state())
+    }
+
+    /// # Listen to presence events
+    ///
+    /// The callback lives as long as the page.
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct User {
+    ///     user_id: String,
+    /// }
+    /// client
+    ///     .channel("document-1")
+    ///     .on_presence(PresenceEvent::Join, |change: Result<PresenceChange<User>, SupabaseError>| {
+    ///         // ...
+    ///     })
+    ///     .subscribe(None);
+    /// ```
+    ///
+    pub fn on_presence<T, F>(&self, event: PresenceEvent, callback: F) -> RealtimeChannel
+    where
+        T: DeserializeOwned,
+        F: Fn(Result<PresenceChange<T>, SupabaseError>) + 'static,
+    {
+        let (channel, closure) = self.listen_presence(event, callback);
+        closure.forget();
+        channel
+    }
+
+    pub(crate) fn listen_presence<T, F>(
+        &self,
+        event: PresenceEvent,
+        callback: F,
+    ) -> (RealtimeChannel, Closure<dyn Fn(JsValue)>)
+    where
+        T: DeserializeOwned,
+        F: Fn(Result<PresenceChange<T>, SupabaseError>) + 'static,
+    {
+        let filter = Object::new();
+        set(&filter, "event", &JsValue::from_str(event.as_str()));
+        let closure: Closure<dyn Fn(JsValue)> = Closure::new(move |payload: JsValue| {
+            callback(PresenceChange::from_js(event, payload));
+        });
+        (self.on("presence", &filter, &closure), closure)
+    }
+}
@@ -0,0 +1,394 @@
+//! Bucket management for [`Storage`], file bodies and options for
+//! [`StorageFileApi`](crate::StorageFileApi).
+
+use js_sys::{Array, Object, Uint8Array};
+use wasm_bindgen::JsValue;
+use web_sys::{Blob, File};
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+use crate::error::set;
+use crate::Storage;
+#[cfg(feature = "serde")]
+use crate::{IntoSupabaseResult, SupabaseError};
+
+/// Options to create or update a bucket
+#[derive(Debug, Clone, Default)]
+pub struct BucketOptions {
+    public: Option<bool>,
+    allowed_mime_types: Option<Vec<String>>,
+    file_size_limit: Option<u64>,
+}
+
+impl BucketOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make files readable without authorization.
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = Some(public);
+        self
+    }
+
+    /// MIME types the bucket accepts, e.g. `image/png` or `image/*`.
+    pub fn allowed_mime_types(mut self, allowed_mime_types: &[&str]) -> Self {
+        self.allowed_mime_types = Some(allowed_mime_types.iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Maximum size of an uploaded file in bytes.
+    pub fn file_size_limit(mut self, bytes: u64) -> Self {
+        self.file_size_limit = Some(bytes);
+        self
+    }
+}
+
+impl From<BucketOptions> for JsValue {
+    fn from(options: BucketOptions) -> Self {
+        let object = Object::new();
+        if let Some(public) = options.public {
+            set(&object, "public", &JsValue::from_bool(public));
+        }
+        if let Some(allowed_mime_types) = &options.allowed_mime_types {
+            let types: Array = allowed_mime_types
+                .iter()
+                .map(|t| JsValue::from_str(t))
+                .collect();
+            set(&object, "allowedMimeTypes", &types);
+        }
+        if let Some(file_size_limit) = options.file_size_limit {
+            set(
+                &object,
+                "fileSizeLimit",
+                &JsValue::from_f64(file_size_limit as f64),
+            );
+        }
+        object.into()
+    }
+}
+
+/// Storage bucket
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Bucket {
+    pub id: String,
+    pub name: String,
+    pub owner: Option<String>,
+    pub public: bool,
+    pub file_size_limit: Option<u64>,
+    pub allowed_mime_types: Option<Vec<String>>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl Storage {
+    /// # Create a bucket
+    ///
+    /// Creates a new Storage bucket
+    ///
+    /// ```ignore
+    /// let res = client
+    ///     .storage()
+    ///     .create_bucket(
+    ///         "avatars",
+    ///         BucketOptions::new()
+    ///             .public(false)
+    ///             .allowed_mime_types(&["image/png"])
+    ///             .file_size_limit(1024),
+    ///     )
+    ///     .await;
+    /// ```
+    ///
+    pub async fn create_bucket(
+        &self,
+        id: &str,
+        options: BucketOptions,
+    ) -> Result<JsValue, JsValue> {
+        self.create_bucket_js(id, options.into()).await
+    }
+
+    /// # Update a bucket
+    ///
+    /// Updates a Storage bucket
+    ///
+    pub async fn update_bucket(
+        &self,
+        id: &str,
+        options: BucketOptions,
+    ) -> Result<JsValue, JsValue> {
+        self.update_bucket_js(id, options.into()).await
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Storage {
+    /// # Retrieve a bucket as [`Bucket`]
+    ///
+    pub async fn get_bucket_typed(&self, id: &str) -> Result<Bucket, SupabaseError> {
+        self.get_bucket(id).await.into_data_as()
+    }
+
+    /// # List all buckets as [`Bucket`]
+    ///
+    pub async fn list_buckets_typed(&self) -> Result<Vec<Bucket>, SupabaseError> {
+        self.list_buckets().await.into_data_as()
+    }
+}
+
+/// File contents to upload
+///
+/// Converts from bytes, a `Blob` or a `File`.
+///
+/// ```ignore
+/// let body: JsValue = FileBody::from(bytes.as_slice()).into();
+/// client.storage().from("avatars").upload("public/avatar1.png", body, JsValue::UNDEFINED).await;
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileBody(JsValue);
+
+impl From<&[u8]> for FileBody {
+    fn from(bytes: &[u8]) -> Self {
+        FileBody(Uint8Array::from(bytes).into())
+    }
+}
+
+impl From<Vec<u8>> for FileBody {
+    fn from(bytes: Vec<u8>) -> Self {
+        FileBody::from(bytes.as_slice())
+    }
+}
+
+impl From<Blob> for FileBody {
+    fn from(blob: Blob) -> Self {
+        FileBody(blob.into())
+    }
+}
+
+impl From<File> for FileBody {
+    fn from(file: File) -> Self {
+        FileBody(file.into())
+    }
+}
+
+impl From<FileBody> for JsValue {
+    fn from(body: FileBody) -> Self {
+        body.0
+    }
+}
+
+/// Options to upload a file
+#[derive(Debug, Clone, Default)]
+pub struct FileOptions {
+    cache_control: Option<String>,
+    content_type: Option<String>,
+    upsert: Option<bool>,
+}
+
+impl FileOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seconds the asset is cached in the browser and the CDN, `3600` by default.
+    pub fn cache_control(mut self, seconds: u32) -> Self {
+        self.cache_control = Some(seconds.to_string());
+        self
+    }
+
+    /// `Content-Type` header of the file, `text/plain;charset=UTF-8` by default.
+    pub fn content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// Overwrite the file if it already exists.
+    pub fn upsert(mut self, upsert: bool) -> Self {
+        self.upsert = Some(upsert);
+        self
+    }
+}
+
+impl From<FileOptions> for JsValue {
+    fn from(options: FileOptions) -> Self {
+        let object = Object::new();
+        if let Some(cache_control) = &options.cache_control {
+            set(&object, "cacheControl", &JsValue::from_str(cache_control));
+        }
+        if let Some(content_type) = &options.content_type {
+            set(&object, "contentType", &JsValue::from_str(content_type));
+        }
+        if let Some(upsert) = options.upsert {
+            set(&object, "upsert", &JsValue::from_bool(upsert));
+        }
+        object.into()
+    }
+}
+
+/// Options to list files
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    limit: Option<u32>,
+    offset: Option<u32>,
+    sort_by: Option<(String, bool)>,
+    search: Option<String>,
+}
+
+impl SearchOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of files to return, `100` by default.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Number of files to skip.
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sort files by column.
+    pub fn sort_by(mut self, column: &str, ascending: bool) -> Self {
+        self.sort_by = Some((column.to_string(), ascending));
+        self
+    }
+
+    /// Only return files whose name contains `search`.
+    pub fn search(mut self, search: &str) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+}
+
+impl From<SearchOptions> for JsValue {
+    fn from(options: SearchOptions) -> Self {
+        let object = Object::new();
+        if let Some(limit) = options.limit {
+            set(&object, "limit", &JsValue::from(limit));
+        }
+        if let Some(offset) = options.offset {
+            set(&object, "offset", &JsValue::from(offset));
+        }
+        if let Some((column, ascending)) = &options.sort_by {
+            let sort_by = Object::new();
+            set(&sort_by, "column", &JsValue::from_str(column));
+            let order = if *ascending { "asc" } else { "desc" };
+            set(&sort_by, "order", &JsValue::from_str(order));
+            set(&object, "sortBy", &sort_by);
+        }
+        if let Some(search) = &options.search {
+            set(&object, "search", &JsValue::from_str(search));
+        }
+        object.into()
+    }
+}
+
+/// Image transformation applied before serving a file
+#[derive(Debug, Clone, Default)]
+pub struct TransformOptions {
+    width: Option<u32>,
+    height: Option<u32>,
+    resize: Option<String>,
+    quality: Option<u32>,
+    format: Option<String>,
+}
+
+impl TransformOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Resize mode: `cover`, `contain` or `fill`.
+    pub fn resize(mut self, resize: &str) -> Self {
+        self.resize = Some(resize.to_string());
+        self
+    }
+
+    /// Quality from 20 to 100.
+    pub fn quality(mut self, quality: u32) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Output format, `origin` keeps the original format.
+    pub fn format(mut self, format: &str) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    fn to_js(&self) -> Object {
+        let object = Object::new();
+        if let Some(width) = self.width {
+            set(&object, "width", &JsValue::from(width));
+        }
+        if let Some(height) = self.height {
+            set(&object, "height", &JsValue::from(height));
+        }
+        if let Some(resize) = &self.resize {
+            set(&object, "resize", &JsValue::from_str(resize));
+        }
+        if let Some(quality) = self.quality {
+            set(&object, "quality", &JsValue::from(quality));
+        }
+        if let Some(format) = &self.format {
+            set(&object, "format", &JsValue::from_str(format));
+        }
+        object
+    }
+}
+
+/// Options to download a file or to create its URL
+#[derive(Debug, Clone, Default)]
+pub struct UrlOptions {
+    download: Option<JsValue>,
+    transform: Option<TransformOptions>,
+}
+
+impl UrlOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make the URL trigger a download, optionally under another file name.
+    pub fn download(mut self, file_name: Option<&str>) -> Self {
+        self.download = Some(match file_name {
+            Some(file_name) => JsValue::from_str(file_name),
+            None => JsValue::TRUE,
+        });
+        self
+    }
+
+    /// Transform the image before serving it.
+    pub fn transform(mut self, transform: TransformOptions) -> Self {
+        self.transform = Some(transform);
+        self
+    }
+}
+
+impl From<UrlOptions> for JsValue {
+    fn from(options: UrlOptions) -> Self {
+        let object = Object::new();
+        if let Some(download) = &options.download {
+            set(&object, "download", download);
+        }
+        if let Some(transform) = &options.transform {
+            set(&object, "transform", &transform.to_js());
+        }
+        object.into()
+    }
+}
@@ -0,0 +1,273 @@
+//! Async streams of realtime and auth events.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_channel::mpsc::{unbounded, UnboundedReceiver};
+use futures_core::Stream;
+use serde::de::DeserializeOwned;
+use wasm_bindgen::{closure::Closure, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+
+use crate::{
+    Auth, AuthChangeEvent, PostgresChangesFilter, PostgresChangesPayload, PresenceChange,
+    PresenceEvent, RealtimeChannel, Session, Subscription, SupabaseError,
+};
+
+/// Stream of realtime events
+///
+/// Ends when the [`RealtimeSubscription`] of the channel is dropped. Streams created by the
+/// `*_stream` methods of [`RealtimeChannel`] own their subscription.
+pub struct RealtimeStream<T> {
+    receiver: UnboundedReceiver<T>,
+    _subscription: Option<RealtimeSubscription>,
+}
+
+impl<T> Stream for RealtimeStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+/// Subscription of a channel with stream listeners
+///
+/// Removes the channel from the client when dropped. The callbacks are released once the
+/// channel is removed, so events arriving before that are still delivered safely.
+pub struct RealtimeSubscription {
+    channel: RealtimeChannel,
+    callbacks: Vec<Closure<dyn Fn(JsValue)>>,
+}
+
+impl Drop for RealtimeSubscription {
+    fn drop(&mut self) {
+        let removed = self.channel.socket().remove_channel_js(&self.channel);
+        let callbacks = std::mem::take(&mut self.callbacks);
+        spawn_local(async move {
+            let _ = JsFuture::from(removed).await;
+            drop(callbacks);
+        });
+    }
+}
+
+/// Listeners of a channel, subscribed at once
+///
+/// realtime-js allows a single `subscribe` per channel, so register every stream first.
+///
+/// ```ignore
+/// use futures::StreamExt;
+///
+/// let mut streams = client.channel("room-1").streams();
+/// let mut messages = streams.broadcast::<Message>("message");
+/// let mut presence = streams.presence::<User>();
+/// let subscription = streams.subscribe();
+/// ```
+pub struct RealtimeStreams {
+    subscription: RealtimeSubscription,
+}
+
+impl RealtimeStreams {
+    /// # Stream database changes
+    ///
+    /// Yields changes matching `filter`.
+    ///
+    pub fn postgres_changes<T: DeserializeOwned + 'static>(
+        &mut self,
+        filter: PostgresChangesFilter,
+    ) -> RealtimeStream<Result<PostgresChangesPayload<T>, SupabaseError>> {
+        let (sender, receiver) = unbounded();
+        let (_, closure) =
+            self.subscription
+                .channel
+                .listen_postgres_changes(filter, move |payload| {
+                    let _ = sender.unbounded_send(payload);
+                });
+        self.listener(receiver, vec![closure])
+    }
+
+    /// # Stream broadcast messages
+    ///
+    /// Yields the payload of every `event` message.
+    ///
+    pub fn broadcast<T: DeserializeOwned + 'static>(
+        &mut self,
+        event: &str,
+    ) -> RealtimeStream<Result<T, SupabaseError>> {
+        let (sender, receiver) = unbounded();
+        let (_, closure) = self
+            .subscription
+            .channel
+            .listen_broadcast(event, move |payload| {
+                let _ = sender.unbounded_send(payload);
+            });
+        self.listener(receiver, vec![closure])
+    }
+
+    /// # Stream presence events
+    ///
+    /// Yields sync, join and leave events.
+    ///
+    pub fn presence<T: DeserializeOwned + 'static>(
+        &mut self,
+    ) -> RealtimeStream<Result<PresenceChange<T>, SupabaseError>> {
+        let (sender, receiver) = unbounded();
+        let callbacks = [
+            PresenceEvent::Sync,
+            PresenceEvent::Join,
+            PresenceEvent::Leave,
+        ]
+        .into_iter()
+        .map(|event| {
+            let sender = sender.clone();
+            let (_, closure) = self
+                .subscription
+                .channel
+                .listen_presence(event, move |change| {
+                    let _ = sender.unbounded_send(change);
+                });
+            closure
+        })
+        .collect();
+        self.listener(receiver, callbacks)
+    }
+
+    /// # Subscribe the channel
+    ///
+    /// Keep the returned subscription alive as long as the streams are used.
+    ///
+    pub fn subscribe(self) -> RealtimeSubscription {
+        self.subscription.channel.subscribe(None);
+        self.subscription
+    }
+
+    fn listener<T>(
+        &mut self,
+        receiver: UnboundedReceiver<T>,
+        callbacks: Vec<Closure<dyn Fn(JsValue)>>,
+    ) -> RealtimeStream<T> {
+        self.subscription.callbacks.extend(callbacks);
+        RealtimeStream {
+            receiver,
+            _subscription: None,
+        }
+    }
+}
+
+impl<T> RealtimeStream<T> {
+    fn owning(mut self, subscription: RealtimeSubscription) -> Self {
+        self._subscription = Some(subscription);
+        self
+    }
+}
+
+impl RealtimeChannel {
+    /// # Stream several kinds of events
+    ///
+    /// See [`RealtimeStreams`].
+    ///
+    pub fn streams(&self) -> RealtimeStreams {
+        RealtimeStreams {
+            subscription: RealtimeSubscription {
+                channel: self.clone(),
+                callbacks: Vec::new(),
+            },
+        }
+    }
+
+    /// # Stream database changes
+    ///
+    /// Listens to changes matching `filter` and subscribes the channel. Use
+    /// [`RealtimeChannel::streams`] to combine it with other listeners.
+    ///
+    /// ```ignore
+    /// use futures::StreamExt;
+    ///
+    /// let mut changes = client.channel("room-1").postgres_changes_stream::<Message>(
+    ///     PostgresChangesFilter::new(PostgresChangesEvent::Insert).table("messages"),
+    /// );
+    /// spawn_local(async move {
+    ///     while let Some(change) = changes.next().await {
+    ///         // ...
+    ///     }
+    /// });
+    /// ```
+    ///
+    pub fn postgres_changes_stream<T: DeserializeOwned + 'static>(
+        &self,
+        filter: PostgresChangesFilter,
+    ) -> RealtimeStream<Result<PostgresChangesPayload<T>, SupabaseError>> {
+        let mut streams = self.streams();
+        let stream = streams.postgres_changes(filter);
+        stream.owning(streams.subscribe())
+    }
+
+    /// # Stream broadcast messages
+    ///
+    /// Yields the payload of every `event` message and subscribes the channel.
+    ///
+    pub fn broadcast_stream<T: DeserializeOwned + 'static>(
+        &self,
+        event: &str,
+    ) -> RealtimeStream<Result<T, SupabaseError>> {
+        let mut streams = self.streams();
+        let stream = streams.broadcast(event);
+        stream.owning(streams.subscribe())
+    }
+
+    /// # Stream presence events
+    ///
+    /// Yields sync, join and leave events and subscribes the channel.
+    ///
+    pub fn presence_stream<T: DeserializeOwned + 'static>(
+        &self,
+    ) -> RealtimeStream<Result<PresenceChange<T>, SupabaseError>> {
+        let mut streams = self.streams();
+        let stream = streams.presence();
+        stream.owning(streams.subscribe())
+    }
+}
+
+/// Stream of auth events
+///
+/// Unsubscribes from auth events when dropped.
+pub struct AuthStateStream {
+    receiver: UnboundedReceiver<(AuthChangeEvent, Result<Option<Session>, SupabaseError>)>,
+    _subscription: Subscription,
+}
+
+impl Stream for AuthStateStream {
+    type Item = (AuthChangeEvent, Result<Option<Session>, SupabaseError>);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
+impl Auth {
+    /// # Stream auth events
+    ///
+    /// Yields the event with the session, see [`Auth::on_auth_state_change_typed`].
+    ///
+    /// ```ignore
+    /// use futures::StreamExt;
+    ///
+    /// let mut events = client.auth().auth_state_stream();
+    /// spawn_local(async move {
+    ///     while let Some((event, session)) = events.next().await {
+    ///         // ...
+    ///     }
+    /// });
+    /// ```
+    ///
+    pub fn auth_state_stream(&self) -> AuthStateStream {
+        let (sender, receiver) = unbounded();
+        let subscription = self.on_auth_state_change_typed(move |event, session| {
+            let _ = sender.unbounded_send((event, session));
+        });
+        AuthStateStream {
+            receiver,
+            _subscription: subscription,
+        }
+    }
+}